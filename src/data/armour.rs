@@ -134,7 +134,6 @@ pub static OZEZANS_PLATING: Buff = Buff { id: 188471, value: 4272f64, value_per_
 // Decrease
 pub static MAJOR_BREACH: Buff = Buff { id: BREACH_MAJOR_ID, value: -5948f64, value_per_stack: 0f64};
 pub static MINOR_BREACH: Buff = Buff { id: BREACH_MINOR_ID, value: -2974f64, value_per_stack: 0f64};
-pub static CRUSHER: Buff = Buff { id: 17906, value: -2108f64, value_per_stack: 0f64}; // Assumes infused. todo
 
 pub static ARMOUR_ALL_BY_ID: Map<u32, &'static Buff> = phf_map! {
     61694 => &MAJOR_RESOLVE,
@@ -145,7 +144,6 @@ pub static ARMOUR_ALL_BY_ID: Map<u32, &'static Buff> = phf_map! {
     44953 => &SCALED_ARMOUR,
     61743 => &MAJOR_BREACH,
     61742 => &MINOR_BREACH,
-    17906 => &CRUSHER,
     64079 => &BULWARK,
     188471 => &OZEZANS_PLATING,
 };
@@ -153,7 +151,8 @@ pub static ARMOUR_ALL_BY_ID: Map<u32, &'static Buff> = phf_map! {
 // Spell Resistance Only
 pub static SISLEAS_DEFENSE: Buff = Buff { id: 139698, value: 15000f64, value_per_stack: 0f64}; // Kyne's Aegis prisoner buff
 pub static SPELL_ATTUNEMENT: Buff = Buff { id: 45262, value: 0f64, value_per_stack: 2310f64}; // Breton Passive. Doubles if afflicted by burning, chilled or concussed.
-pub static SPELL_RESIST_POTION: Buff = Buff { id: 64562, value: 5280f64, value_per_stack: 0f64};
+pub const SPELL_RESIST_POTION_ID: u32 = 64562;
+pub static SPELL_RESIST_POTION: Buff = Buff { id: SPELL_RESIST_POTION_ID, value: 5280f64, value_per_stack: 0f64};
 pub static SPELL_WARDING: Buff = Buff { id: 45559, value: 0f64, value_per_stack: 726f64}; // Light Armour Passive
 
 pub static SPELL_RESISTANCE_BY_ID: Map<u32, &'static Buff> = phf_map! {
@@ -164,7 +163,8 @@ pub static SPELL_RESISTANCE_BY_ID: Map<u32, &'static Buff> = phf_map! {
 };
 
 // Physical Resistance Only
-pub static PHYSICAL_RESIST_POTION: Buff = Buff { id: 64564, value: 5280f64, value_per_stack: 0f64};
+pub const PHYSICAL_RESIST_POTION_ID: u32 = 64564;
+pub static PHYSICAL_RESIST_POTION: Buff = Buff { id: PHYSICAL_RESIST_POTION_ID, value: 5280f64, value_per_stack: 0f64};
 
 pub static PHYSICAL_RESISTANCE_BY_ID: Map<u32, &'static Buff> = phf_map! {
     64564 => &PHYSICAL_RESIST_POTION,
@@ -0,0 +1,71 @@
+use crate::data::item_type::ItemType;
+use crate::models::damage::DamageType;
+use crate::models::resource::PlayerAttributeType;
+
+/// Time a fully charged heavy attack is channelled for before it lands.
+pub const HEAVY_ATTACK_CHANNEL_DEFAULT_MS: u64 = 1500;
+/// Global cooldown between skills, which light attacks are woven into.
+pub const GLOBAL_COOLDOWN_MS: u64 = 1000;
+
+/// A light or heavy attack. Damage is `coefficient * (power + max resource / 10.5)`.
+#[derive(Debug, PartialEq)]
+pub struct Attack {
+    pub id: u32,
+    pub damage_type: DamageType,
+    pub resource: PlayerAttributeType,
+    pub coefficient: f32,
+    pub heavy: bool,
+    pub channel_ms: u64,
+    /// Restored to `resource` when the attack lands.
+    pub restore: u32,
+}
+
+pub static UNARMED_LIGHT_ATTACK: Attack = Attack { id: 23604, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.1, heavy: false, channel_ms: 0, restore: 0 };
+pub static ONE_HANDED_AND_SHIELD_LIGHT_ATTACK: Attack = Attack { id: 15435, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.2, heavy: false, channel_ms: 0, restore: 0 };
+pub static DUAL_WIELD_LIGHT_ATTACK: Attack = Attack { id: 16499, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.2, heavy: false, channel_ms: 0, restore: 0 };
+pub static TWO_HANDED_LIGHT_ATTACK: Attack = Attack { id: 16037, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.2, heavy: false, channel_ms: 0, restore: 0 };
+pub static BOW_LIGHT_ATTACK: Attack = Attack { id: 16688, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.2, heavy: false, channel_ms: 0, restore: 0 };
+pub static FIRE_STAFF_LIGHT_ATTACK: Attack = Attack { id: 16165, damage_type: DamageType::FIRE, resource: PlayerAttributeType::Magicka, coefficient: 0.2, heavy: false, channel_ms: 0, restore: 0 };
+pub static FROST_STAFF_LIGHT_ATTACK: Attack = Attack { id: 16277, damage_type: DamageType::COLD, resource: PlayerAttributeType::Magicka, coefficient: 0.2, heavy: false, channel_ms: 0, restore: 0 };
+pub static LIGHTNING_STAFF_LIGHT_ATTACK: Attack = Attack { id: 18350, damage_type: DamageType::SHOCK, resource: PlayerAttributeType::Magicka, coefficient: 0.2, heavy: false, channel_ms: 0, restore: 0 };
+pub static HEALING_STAFF_LIGHT_ATTACK: Attack = Attack { id: 16145, damage_type: DamageType::MAGIC, resource: PlayerAttributeType::Magicka, coefficient: 0.2, heavy: false, channel_ms: 0, restore: 0 };
+
+pub static UNARMED_HEAVY_ATTACK: Attack = Attack { id: 18430, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.3, heavy: true, channel_ms: HEAVY_ATTACK_CHANNEL_DEFAULT_MS, restore: 1000 };
+pub static ONE_HANDED_AND_SHIELD_HEAVY_ATTACK: Attack = Attack { id: 17162, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.6, heavy: true, channel_ms: HEAVY_ATTACK_CHANNEL_DEFAULT_MS, restore: 2000 };
+pub static DUAL_WIELD_HEAVY_ATTACK: Attack = Attack { id: 16420, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.6, heavy: true, channel_ms: HEAVY_ATTACK_CHANNEL_DEFAULT_MS, restore: 2000 };
+pub static TWO_HANDED_HEAVY_ATTACK: Attack = Attack { id: 16041, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.7, heavy: true, channel_ms: HEAVY_ATTACK_CHANNEL_DEFAULT_MS, restore: 2000 };
+pub static BOW_HEAVY_ATTACK: Attack = Attack { id: 16691, damage_type: DamageType::PHYSICAL, resource: PlayerAttributeType::Stamina, coefficient: 0.6, heavy: true, channel_ms: HEAVY_ATTACK_CHANNEL_DEFAULT_MS, restore: 2000 };
+pub static FIRE_STAFF_HEAVY_ATTACK: Attack = Attack { id: 15383, damage_type: DamageType::FIRE, resource: PlayerAttributeType::Magicka, coefficient: 0.7, heavy: true, channel_ms: HEAVY_ATTACK_CHANNEL_DEFAULT_MS, restore: 2000 };
+pub static FROST_STAFF_HEAVY_ATTACK: Attack = Attack { id: 16261, damage_type: DamageType::COLD, resource: PlayerAttributeType::Magicka, coefficient: 0.5, heavy: true, channel_ms: HEAVY_ATTACK_CHANNEL_DEFAULT_MS, restore: 2000 };
+pub static LIGHTNING_STAFF_HEAVY_ATTACK: Attack = Attack { id: 18396, damage_type: DamageType::SHOCK, resource: PlayerAttributeType::Magicka, coefficient: 0.8, heavy: true, channel_ms: 2000, restore: 2000 };
+pub static HEALING_STAFF_HEAVY_ATTACK: Attack = Attack { id: 16212, damage_type: DamageType::MAGIC, resource: PlayerAttributeType::Magicka, coefficient: 0.5, heavy: true, channel_ms: HEAVY_ATTACK_CHANNEL_DEFAULT_MS, restore: 2000 };
+
+/// Light attack of the main hand weapon. One handed weapons attack as dual wield unless the off hand is a shield.
+pub fn get_light_attack(main_hand: Option<&ItemType>, off_hand: Option<&ItemType>) -> &'static Attack {
+    match main_hand {
+        Some(ItemType::Axe | ItemType::Dagger | ItemType::Mace | ItemType::Sword) if off_hand == Some(&ItemType::Shield) => &ONE_HANDED_AND_SHIELD_LIGHT_ATTACK,
+        Some(ItemType::Axe | ItemType::Dagger | ItemType::Mace | ItemType::Sword) => &DUAL_WIELD_LIGHT_ATTACK,
+        Some(ItemType::TwoHandedAxe | ItemType::TwoHandedMace | ItemType::TwoHandedSword) => &TWO_HANDED_LIGHT_ATTACK,
+        Some(ItemType::Bow) => &BOW_LIGHT_ATTACK,
+        Some(ItemType::FireStaff) => &FIRE_STAFF_LIGHT_ATTACK,
+        Some(ItemType::FrostStaff) => &FROST_STAFF_LIGHT_ATTACK,
+        Some(ItemType::LightningStaff) => &LIGHTNING_STAFF_LIGHT_ATTACK,
+        Some(ItemType::HealingStaff) => &HEALING_STAFF_LIGHT_ATTACK,
+        _ => &UNARMED_LIGHT_ATTACK,
+    }
+}
+
+pub fn get_heavy_attack(main_hand: Option<&ItemType>, off_hand: Option<&ItemType>) -> &'static Attack {
+    match main_hand {
+        Some(ItemType::Axe | ItemType::Dagger | ItemType::Mace | ItemType::Sword) if off_hand == Some(&ItemType::Shield) => &ONE_HANDED_AND_SHIELD_HEAVY_ATTACK,
+        Some(ItemType::Axe | ItemType::Dagger | ItemType::Mace | ItemType::Sword) => &DUAL_WIELD_HEAVY_ATTACK,
+        Some(ItemType::TwoHandedAxe | ItemType::TwoHandedMace | ItemType::TwoHandedSword) => &TWO_HANDED_HEAVY_ATTACK,
+        Some(ItemType::Bow) => &BOW_HEAVY_ATTACK,
+        Some(ItemType::FireStaff) => &FIRE_STAFF_HEAVY_ATTACK,
+        Some(ItemType::FrostStaff) => &FROST_STAFF_HEAVY_ATTACK,
+        Some(ItemType::LightningStaff) => &LIGHTNING_STAFF_HEAVY_ATTACK,
+        Some(ItemType::HealingStaff) => &HEALING_STAFF_HEAVY_ATTACK,
+        _ => &UNARMED_HEAVY_ATTACK,
+    }
+}
+
@@ -0,0 +1,31 @@
+use phf::{Map, phf_map};
+
+use crate::data::skill::*;
+
+/// Cast time or channel of an ability, as from `GetAbilityCastInfo` and `GetAbilityFrequencyMS`. Abilities without one are instant.
+#[derive(Debug, PartialEq)]
+pub struct CastInfo {
+    pub id: u32,
+    /// Channels hit while they are channelled, casts only hit once the cast is over.
+    pub channeled: bool,
+    pub duration_ms: u64,
+    /// Time between the hits of a channel, the first of which lands as it starts.
+    pub frequency_ms: u64,
+}
+
+impl CastInfo {
+    /// Number of times the ability hits over the whole cast or channel. A channel without a frequency hits once.
+    pub fn get_ticks(&self) -> u32 {
+        if self.channeled && self.frequency_ms > 0 {(self.duration_ms / self.frequency_ms) as u32 + 1} else {1}
+    }
+}
+
+pub static CRYSTAL_SHARD: CastInfo = CastInfo { id: CRYSTAL_SHARD_ID, channeled: false, duration_ms: 800, frequency_ms: 0 };
+pub static PUNCTURING_STRIKES: CastInfo = CastInfo { id: PUNCTURING_STRIKES_ID, channeled: true, duration_ms: 900, frequency_ms: 300 };
+pub static RADIANT_DESTRUCTION: CastInfo = CastInfo { id: RADIANT_DESTRUCTION_ID, channeled: true, duration_ms: 3000, frequency_ms: 1000 };
+
+pub static CAST_INFO_BY_ID: Map<u32, &'static CastInfo> = phf_map! {
+    43714u32 => &CRYSTAL_SHARD,
+    26144u32 => &PUNCTURING_STRIKES,
+    63029u32 => &RADIANT_DESTRUCTION,
+};
@@ -1,4 +1,6 @@
-use crate::data::{StatBuff as Buff, major_minor::*};
+use phf::{Map, phf_map};
+
+use crate::data::{StatBuff as Buff, major_minor::*, skill::{BOUND_AEGIS_ID, UNBREAKABLE_FATE_ID}};
 
 // damage done
 pub static BERSERK_MAJOR: Buff = Buff { id: BERSERK_MAJOR_ID, value: 10f64, value_per_stack: 0f64};
@@ -19,11 +21,35 @@ pub static SLAYER_MINOR: Buff = Buff { id: SLAYER_MINOR_ID, value: 5f64, value_p
 // standard of might
 // thrive in chaos
 
+pub static DAMAGE_DONE_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    61745 => &BERSERK_MAJOR,
+    61744 => &BERSERK_MINOR,
+};
+
+// single target damage done
+// pub static DEADLY_AIM: Buff = Buff { id: /* */, value: 0f64, value_per_stack: 2f64}; // Red CP, per stage
+
+pub static SINGLE_TARGET_DAMAGE_DONE_BY_ID: Map<u32, &'static Buff> = phf_map! {
+};
+
+// area of effect damage done
+// pub static BITING_AURA: Buff = Buff { id: /* */, value: 0f64, value_per_stack: 2f64}; // Red CP, per stage
+
+pub static AOE_DAMAGE_DONE_BY_ID: Map<u32, &'static Buff> = phf_map! {
+};
+
 // damage taken
 pub static PROTECTION_MAJOR: Buff = Buff { id: PROTECTION_MAJOR_ID, value: -10f64, value_per_stack: 0f64};
 pub static PROTECTION_MINOR: Buff = Buff { id: PROTECTION_MINOR_ID, value: -5f64, value_per_stack: 0f64};
 pub static VULNERABILITY_MINOR: Buff = Buff { id: VULNERABILITY_MINOR_ID, value: 5f64, value_per_stack: 0f64};
 pub static VULNERABILITY_MAJOR: Buff = Buff { id: VULNERABILITY_MAJOR_ID, value: 10f64, value_per_stack: 0f64};
+
+pub static DAMAGE_TAKEN_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    61722 => &PROTECTION_MAJOR,
+    61721 => &PROTECTION_MINOR,
+    79717 => &VULNERABILITY_MINOR,
+    106754 => &VULNERABILITY_MAJOR,
+};
 // armour bonuses
 // standard of might
 
@@ -55,17 +81,31 @@ pub static VULNERABILITY_MAJOR: Buff = Buff { id: VULNERABILITY_MAJOR_ID, value:
 
 
 // block mitigation
-// black-glove grounding
-// unbreakable fate
-// bound aegis
-// full belly barricade
+// black-glove grounding and full belly barricade are `SetBonusType::BlockMitigation`, applied by the block component.
+pub static UNBREAKABLE_FATE: Buff = Buff { id: UNBREAKABLE_FATE_ID, value: 10f64, value_per_stack: 0f64};
+pub static BOUND_AEGIS: Buff = Buff { id: BOUND_AEGIS_ID, value: 5f64, value_per_stack: 0f64}; // while slotted
 
+pub static BLOCK_MITIGATION_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    186477 => &UNBREAKABLE_FATE,
+    24163 => &BOUND_AEGIS,
+};
 
 // block cost
-// full belly barricade
-// ancient knowledge
-// bracing enchant
-// defensive stance
-// fortress
+// bracing enchant is `EnchantType::ReduceBlockAndBash`, applied with Sturdy by the cost component.
+// full belly barricade is `SetBonusType::BlockCost`, applied by the block component.
+// pub static ANCIENT_KNOWLEDGE: Buff = Buff { id: /* */, value: 8f64, value_per_stack: 0f64}; // Arcanist passive
+pub static DEFENSIVE_STANCE: Buff = Buff { id: 38312, value: 20f64, value_per_stack: 0f64}; // while slotted
+// pub static FORTRESS: Buff = Buff { id: /* */, value: 0f64, value_per_stack: 18f64}; // One hand and shield passive, per rank
+
+pub static BLOCK_COST_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    38312 => &DEFENSIVE_STANCE,
+};
+
+// damage shield strength
+pub static BASTION: Buff = Buff { id: 142079, value: 0f64, value_per_stack: 3f64}; // Blue CP, per stage
+
+pub static SHIELD_STRENGTH_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    142079 => &BASTION,
+};
 // armour bonuses
 // 
\ No newline at end of file
@@ -1,4 +1,6 @@
-use crate::data::item_type::ItemQuality;
+use crate::data::item_type::{EnchantType, ItemQuality};
+use crate::models::damage::DamageType;
+use crate::models::resource::PlayerAttributeType;
 
 enum EnchantLevel {
     One,
@@ -519,11 +521,146 @@ pub fn get_enchant_jewellery_increase_spell_damage(effective_level: &u8, quality
     }
 }
 
-// stam, mag, health recovery
-// potion duration/cooldown
+/// Health, magicka or stamina recovery, which share the same values.
+pub fn get_enchant_jewellery_recovery(effective_level: &u8, quality: &ItemQuality) -> f32 {
+    use ItemQuality::*;
+
+    let enchant_level = match match_effective_level_to_enchant_level(effective_level) {
+        Some(l) => l,
+        None => return 0.0,
+    };
+
+    let values = shared_jewellery_power_values(enchant_level);
+
+    match quality {
+        Normal    => values[0],
+        Fine      => values[1],
+        Superior  => values[2],
+        Epic      => values[3],
+        Legendary => values[4],
+    }
+}
+
+/// Health, Magicka, Stamina
+pub fn get_enchant_jewellery_prismatic_recovery(effective_level: &u8, quality: &ItemQuality) -> (f32, f32, f32) {
+    let recovery = (get_enchant_jewellery_recovery(effective_level, quality) / 2.0).floor();
+
+    (recovery, recovery, recovery)
+}
+
+/// Seconds removed from the potion cooldown. Only known at CP160 legendary.
+pub fn get_enchant_jewellery_reduce_potion_cooldown(effective_level: &u8, quality: &ItemQuality) -> f32 {
+    scale_from_legendary_value(5.0, effective_level, quality)
+}
+
+/// Seconds added to the duration of potion effects. Only known at CP160 legendary.
+pub fn get_enchant_jewellery_increase_potion_effectiveness(effective_level: &u8, quality: &ItemQuality) -> f32 {
+    scale_from_legendary_value(3.5, effective_level, quality)
+}
+
+pub const WEAPON_ENCHANT_COOLDOWN_MS: u64 = 5000;
+pub const WEAPON_ENCHANT_DURATION_MS: u64 = 5000;
+
+pub const FIERY_WEAPON_ID: u32 = 17895;
+pub const FROZEN_WEAPON_ID: u32 = 17897;
+pub const CHARGED_WEAPON_ID: u32 = 17899;
+pub const POISONED_WEAPON_ID: u32 = 17902;
+pub const BEFOULED_WEAPON_ID: u32 = 17904;
+pub const CRUSHER_ID: u32 = 17906;
+pub const BERSERKER_ID: u32 = 21230;
+// These weapon enchant procs don't show on logs, so they get fake IDs from the 4002000 range.
+pub const OBLIVION_DAMAGE_ID: u32 = 4002000;
+pub const ABSORB_HEALTH_ID: u32 = 4002001;
+pub const ABSORB_MAGICKA_ID: u32 = 4002002;
+pub const ABSORB_STAMINA_ID: u32 = 4002003;
+pub const WEAKENING_ID: u32 = 4002004;
+pub const PRISMATIC_ONSLAUGHT_ID: u32 = 4002005;
+pub const HARDENING_ID: u32 = 17317;
+
+#[derive(Debug, PartialEq)]
+pub enum WeaponEnchantEffect {
+    Damage(DamageType),
+    /// Deals magic damage and restores as much of the given resource to the wielder.
+    Absorb(PlayerAttributeType),
+    /// Debuffs the target's physical and spell resistance.
+    ReduceArmour,
+    /// Debuffs the target's weapon and spell damage.
+    ReducePower,
+    /// Buffs the wielder's weapon and spell damage.
+    IncreasePower,
+    /// Grants the wielder a damage shield.
+    DamageShield,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WeaponEnchant {
+    pub id: u32,
+    pub effect: WeaponEnchantEffect,
+    /// CP160 legendary value before Infused.
+    legendary_value: f32,
+}
+
+pub static FIERY_WEAPON: WeaponEnchant = WeaponEnchant { id: FIERY_WEAPON_ID, effect: WeaponEnchantEffect::Damage(DamageType::FIRE), legendary_value: 1742.0 };
+pub static FROZEN_WEAPON: WeaponEnchant = WeaponEnchant { id: FROZEN_WEAPON_ID, effect: WeaponEnchantEffect::Damage(DamageType::COLD), legendary_value: 1742.0 };
+pub static CHARGED_WEAPON: WeaponEnchant = WeaponEnchant { id: CHARGED_WEAPON_ID, effect: WeaponEnchantEffect::Damage(DamageType::SHOCK), legendary_value: 1742.0 };
+pub static POISONED_WEAPON: WeaponEnchant = WeaponEnchant { id: POISONED_WEAPON_ID, effect: WeaponEnchantEffect::Damage(DamageType::POISON), legendary_value: 1742.0 };
+pub static BEFOULED_WEAPON: WeaponEnchant = WeaponEnchant { id: BEFOULED_WEAPON_ID, effect: WeaponEnchantEffect::Damage(DamageType::DISEASE), legendary_value: 1742.0 };
+pub static OBLIVION_DAMAGE: WeaponEnchant = WeaponEnchant { id: OBLIVION_DAMAGE_ID, effect: WeaponEnchantEffect::Damage(DamageType::OBLIVION), legendary_value: 1742.0 };
+pub static ABSORB_HEALTH: WeaponEnchant = WeaponEnchant { id: ABSORB_HEALTH_ID, effect: WeaponEnchantEffect::Absorb(PlayerAttributeType::Health), legendary_value: 1742.0 };
+pub static ABSORB_MAGICKA: WeaponEnchant = WeaponEnchant { id: ABSORB_MAGICKA_ID, effect: WeaponEnchantEffect::Absorb(PlayerAttributeType::Magicka), legendary_value: 1742.0 };
+pub static ABSORB_STAMINA: WeaponEnchant = WeaponEnchant { id: ABSORB_STAMINA_ID, effect: WeaponEnchantEffect::Absorb(PlayerAttributeType::Stamina), legendary_value: 1742.0 };
+pub static PRISMATIC_ONSLAUGHT: WeaponEnchant = WeaponEnchant { id: PRISMATIC_ONSLAUGHT_ID, effect: WeaponEnchantEffect::Damage(DamageType::MAGIC), legendary_value: 1742.0 };
+pub static CRUSHER: WeaponEnchant = WeaponEnchant { id: CRUSHER_ID, effect: WeaponEnchantEffect::ReduceArmour, legendary_value: 1622.0 };
+pub static WEAKENING: WeaponEnchant = WeaponEnchant { id: WEAKENING_ID, effect: WeaponEnchantEffect::ReducePower, legendary_value: 348.0 };
+pub static BERSERKER: WeaponEnchant = WeaponEnchant { id: BERSERKER_ID, effect: WeaponEnchantEffect::IncreasePower, legendary_value: 348.0 };
+pub static HARDENING: WeaponEnchant = WeaponEnchant { id: HARDENING_ID, effect: WeaponEnchantEffect::DamageShield, legendary_value: 2418.0 };
+
+pub fn get_weapon_enchant(glyph: &EnchantType) -> Option<&'static WeaponEnchant> {
+    use EnchantType::*;
+    Some(match glyph {
+        FieryWeapon => &FIERY_WEAPON,
+        FrozenWeapon => &FROZEN_WEAPON,
+        ChargedWeapon => &CHARGED_WEAPON,
+        PoisonedWeapon => &POISONED_WEAPON,
+        BefouledWeapon => &BEFOULED_WEAPON,
+        OblivionDamage => &OBLIVION_DAMAGE,
+        AbsorbHealth => &ABSORB_HEALTH,
+        AbsorbMagicka => &ABSORB_MAGICKA,
+        AbsorbStamina => &ABSORB_STAMINA,
+        PrismaticOnslaught => &PRISMATIC_ONSLAUGHT,
+        ReduceArmor => &CRUSHER,
+        ReducePower => &WEAKENING,
+        Beserker => &BERSERKER,
+        DamageShield => &HARDENING,
+        _ => return None,
+    })
+}
+
+/// Scales a CP160 legendary value to other levels and qualities along the armour glyph curve.
+fn scale_from_legendary_value(legendary_value: f32, effective_level: &u8, quality: &ItemQuality) -> f32 {
+    use ItemQuality::*;
+
+    let enchant_level = match match_effective_level_to_enchant_level(effective_level) {
+        Some(l) => l,
+        None => return 0.0,
+    };
+
+    let values = shared_armour_values(enchant_level);
+    let value = match quality {
+        Normal    => values[0],
+        Fine      => values[1],
+        Superior  => values[2],
+        Epic      => values[3],
+        Legendary => values[4],
+    };
 
-// weapon enchants are more like.. skills I guess?
-// treat them as implementable skills and auto-cast them for the player in certain situations onto enemy[0]?
+    legendary_value * value / shared_armour_values(&EnchantLevel::CPOneSixty)[4]
+}
+
+/// Weapon glyph values are only known at CP160 legendary.
+pub fn get_enchant_weapon_value(enchant: &WeaponEnchant, effective_level: &u8, quality: &ItemQuality) -> f32 {
+    scale_from_legendary_value(enchant.legendary_value, effective_level, quality).round()
+}
 
 #[cfg(test)]
 mod tests {
@@ -605,4 +742,12 @@ mod tests {
         assert_eq!(magicka, 62.0);
         assert_eq!(stamina, 62.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_weapon_enchant_values_scale_from_legendary() {
+        assert_eq!(get_enchant_weapon_value(&CRUSHER, &66, &Legendary), 1622.0);
+        assert!(get_enchant_weapon_value(&CRUSHER, &66, &Epic) < 1622.0);
+        assert_eq!(get_enchant_weapon_value(&CRUSHER, &0, &Legendary), 0.0);
+        assert_eq!(get_weapon_enchant(&EnchantType::DamageShield), Some(&HARDENING));
+    }
+}
@@ -0,0 +1,25 @@
+use phf::{Map, phf_map};
+
+use crate::data::{StatBuff as Buff, major_minor::*};
+
+// healing done
+pub static MENDING_MAJOR: Buff = Buff { id: MENDING_MAJOR_ID, value: 16f64, value_per_stack: 0f64};
+pub static MENDING_MINOR: Buff = Buff { id: MENDING_MINOR_ID, value: 8f64, value_per_stack: 0f64};
+
+pub static HEALING_DONE_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    61711 => &MENDING_MAJOR,
+    61710 => &MENDING_MINOR,
+};
+
+// healing taken
+pub static VITALITY_MAJOR: Buff = Buff { id: VITALITY_MAJOR_ID, value: 12f64, value_per_stack: 0f64};
+pub static VITALITY_MINOR: Buff = Buff { id: VITALITY_MINOR_ID, value: 6f64, value_per_stack: 0f64};
+pub static DEFILE_MAJOR: Buff = Buff { id: DEFILE_MAJOR_ID, value: -12f64, value_per_stack: 0f64};
+pub static DEFILE_MINOR: Buff = Buff { id: DEFILE_MINOR_ID, value: -6f64, value_per_stack: 0f64};
+
+pub static HEALING_TAKEN_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    61713 => &VITALITY_MAJOR,
+    61549 => &VITALITY_MINOR,
+    61727 => &DEFILE_MAJOR,
+    61726 => &DEFILE_MINOR,
+};
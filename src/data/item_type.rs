@@ -125,7 +125,7 @@ pub fn is_armour(item: &ItemType) -> bool {
     matches!(item, ItemType::Light | ItemType::Medium | ItemType::Heavy | ItemType::Shield)
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum ItemQuality {
     Normal,
     Fine,
@@ -134,7 +134,7 @@ pub enum ItemQuality {
     Legendary,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum GearSlot {
     Head,
     Shoulders,
@@ -154,7 +154,7 @@ pub enum GearSlot {
     BackupPoison,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum GearTrait {
     JewelryBloodthirsty,
     JewelryHarmony,
@@ -196,7 +196,7 @@ pub enum GearTrait {
 // Note that enchants are only bound to specific gear pieces by the rules of what can be applied in the game. This is why they are listed here as one enum.
 // In fact, bugged items exist on live servers from trials that have armour enchantments on weapons, such as a +Max Stamina Lightning Staff
 // If such an item exists but with traits instead of enchants then please let me know.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum EnchantType {
     AbsorbHealth,
     AbsorbMagicka,
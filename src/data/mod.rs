@@ -7,11 +7,22 @@ pub mod traits;
 pub mod armour;
 pub mod resource;
 pub mod enchant;
+pub mod poison;
+pub mod potion;
 pub mod sets;
 // pub mod health_lookup;
 // pub mod resource_lookup;
 pub mod critical_chance;
 pub mod damage_modifier;
+pub mod healing;
+pub mod status_effect;
+pub mod skill_damage;
+pub mod pet;
+pub mod ultimate;
+pub mod attack;
+pub mod cast;
+pub mod skill_timing;
+pub mod names;
 
 #[derive(PartialEq, Debug)]
 pub struct StatBuff {
@@ -0,0 +1,210 @@
+use phf::{Map, phf_map};
+
+/// Readable names for the IDs used by the stat components.
+pub static NAMES_BY_ID: Map<u32, &'static str> = phf_map! {
+    // critical_damage
+    13984 => "The Shadow",
+    44046 => "Piercing Spear",
+    45060 => "Hemorrhage",
+    45301 => "Feline Ambush",
+    45430 => "Heavy Weapons",
+    45482 => "Twin Blade and Blunt",
+    45564 => "Dexterity",
+    61746 => "Minor Force",
+    61747 => "Major Force",
+    79113 => "Minor Enervation",
+    86069 => "Advanced Species",
+    154737 => "Sul-Xan's Torment",
+    155150 => "Harpooner's Wading Kilt",
+    194875 => "Fated Fortune",
+    220015 => "Lucent Echoes",
+    220315 => "Mora Scribe's Thesis",
+    142610 => "Flame Weakness",
+    142652 => "Frost Weakness",
+    142653 => "Shock Weakness",
+    145975 => "Minor Brittle",
+    145977 => "Major Brittle",
+
+    // critical_chance
+    13975 => "The Thief",
+    45561 => "Prodigy",
+    141898 => "Precision",
+    45492 => "Accuracy",
+    118506 => "Auriel's Bow",
+    117965 => "Volendrung",
+    139696 => "Skoria's Savagery",
+    45562 => "Concentration",
+    50978 => "Berserking Warrior",
+    219681 => "Highland Sentinel",
+    45038 => "Master Assassin",
+    99204 => "Mechanical Acuity",
+    45053 => "Pressure Points",
+    79895 => "Minor Uncertainty",
+    45494 => "Vinedusk Training",
+    61689 => "Major Prophecy",
+    61691 => "Minor Prophecy",
+    61667 => "Major Savagery",
+    61666 => "Minor Savagery",
+
+    // power
+    147417 => "Minor Courage",
+    109966 => "Major Courage",
+    45272 => "Ruination",
+    45276 => "Elemental Talent",
+    45312 => "Swift Warrior",
+    215727 => "Sunderer",
+    21230 => "Berserker Enchantment",
+    61771 => "Powerful Assault",
+    45195 => "Expert Mage",
+    163401 => "Aura of Pride",
+    172621 => "Pearlescent Ward",
+    122729 => "Seething Fury",
+    184860 => "Harnessed Quintessence",
+    45572 => "Agility",
+    45596 => "Slayer",
+    44732 => "Balanced Warrior",
+    46139 => "Savage Strength",
+    13979 => "The Apprentice",
+    61685 => "Minor Sorcery",
+    61687 => "Major Sorcery",
+    13940 => "The Warrior",
+    61665 => "Major Brutality",
+    61662 => "Minor Brutality",
+
+    // armour
+    61694 => "Major Resolve",
+    61693 => "Minor Resolve",
+    86190 => "Frozen Armour",
+    45533 => "Resolve",
+    45306 => "Rugged",
+    44953 => "Scaled Armour",
+    61743 => "Major Breach",
+    61742 => "Minor Breach",
+    64079 => "Bulwark",
+    4001000 => "Fortified",
+    188471 => "Ozezan's Plating",
+    139698 => "Sislea's Defense",
+    45262 => "Spell Attunement",
+    64562 => "Spell Resist Potion",
+    45559 => "Spell Warding",
+    64564 => "Physical Resist Potion",
+    45304 => "Resist Frost",
+    45255 => "Argonian Resistance",
+    45319 => "Resist Affliction",
+    116194 => "Dismember",
+    174250 => "Force of Nature",
+    45296 => "Hunter's Eye",
+    184887 => "Splintered Secrets",
+    141895 => "Piercing",
+
+    // resource
+    117848 => "Lunar Blessings",
+    32624 => "Blood Scion",
+    38932 => "Swarming Scion",
+    38931 => "Perfect Scion",
+    220010 => "Xoryn's Masterpiece",
+    45280 => "Imperial Mettle",
+    45267 => "Dynamic",
+    45247 => "Resourceful",
+    45309 => "Brawny",
+    117754 => "Conditioning",
+    147888 => "Tireless Discipline",
+    40224 => "Aggressive Horn",
+    55386 => "Undaunted Mettle",
+    33469 => "Werewolf Transformation",
+    45199 => "Expert Summoner",
+    47362 => "Ebon Armoury",
+    116272 => "Minor Toughness",
+    115001 => "Bone Goliath Transformation",
+    50907 => "Tough",
+    84672 => "Unflinching Rage",
+    149311 => "Hero's Vigor",
+    45084 => "Dark Vigor",
+    61733 => "Minor Mangle",
+    45546 => "Juggernaut",
+    117970 => "Syrabane's Boon",
+    45260 => "Gift of Magnus",
+    149305 => "Eldritch Insight",
+    45603 => "Magicka Controller",
+    45150 => "Magicka Flood",
+    86673 => "Lava Foot Soup-and-Saltrice",
+    72824 => "Orzorga's Smoked Bear Haunch",
+    61257 => "Magicka Bi-Stat Food",
+    61255 => "Stamina Bi-Stat Food",
+    127596 => "Bewitched Sugar Skulls",
+    107789 => "Artaeum Takeaway Broth",
+    100498 => "Clockwork Citrus Filet",
+    84720 => "Ghastly Eye Bowl",
+    89971 => "Jewels of Misrule",
+    107748 => "Artaeum Pickled Fish Bowl",
+    127572 => "Pack Leader's Bone Broth",
+    127531 => "Corrupting Bloody Mara",
+    84731 => "Witchmother's Potent Brew",
+    89957 => "Dubious Camoran Throne",
+    89973 => "Orzorga's Red Frothgar",
+    61261 => "Stamina Parse Food",
+    61260 => "Magicka Parse Food",
+    89955 => "Candied Jester's Coins",
+
+    // damage_modifier
+    61722 => "Major Protection",
+    61721 => "Minor Protection",
+    79717 => "Minor Vulnerability",
+    106754 => "Major Vulnerability",
+    186477 => "Unbreakable Fate",
+    24163 => "Bound Aegis",
+    38312 => "Defensive Stance",
+    142079 => "Bastion",
+
+    // healing
+    61711 => "Major Mending",
+    61710 => "Minor Mending",
+    61713 => "Major Vitality",
+    61549 => "Minor Vitality",
+    61727 => "Major Defile",
+    61726 => "Minor Defile",
+
+    // status_effect
+    18084 => "Burning",
+    95136 => "Chilled",
+    95134 => "Concussed",
+    21929 => "Poisoned",
+    178127 => "Diseased",
+    148801 => "Hemorrhaging",
+    178123 => "Sundered",
+    178118 => "Overcharged",
+
+    // pet
+    23304 => "Summon Unstable Familiar",
+    24636 => "Summon Twilight Tormentor",
+    85982 => "Feral Guardian",
+    114317 => "Skeletal Mage",
+
+    // attack
+    23604 => "Unarmed Light Attack",
+    15435 => "One Hand and Shield Light Attack",
+    16499 => "Dual Wield Light Attack",
+    16037 => "Two Handed Light Attack",
+    16688 => "Bow Light Attack",
+    16165 => "Fire Staff Light Attack",
+    16277 => "Frost Staff Light Attack",
+    18350 => "Lightning Staff Light Attack",
+    16145 => "Restoration Staff Light Attack",
+    18430 => "Unarmed Heavy Attack",
+    17162 => "One Hand and Shield Heavy Attack",
+    16420 => "Dual Wield Heavy Attack",
+    16041 => "Two Handed Heavy Attack",
+    16691 => "Bow Heavy Attack",
+    15383 => "Fire Staff Heavy Attack",
+    16261 => "Frost Staff Heavy Attack",
+    18396 => "Lightning Staff Heavy Attack",
+    16212 => "Restoration Staff Heavy Attack",
+
+    // synergy
+    63507 => "Combustion",
+    26832 => "Blessed Shards",
+};
+
+pub fn get_name(id: &u32) -> Option<&'static str> {
+    NAMES_BY_ID.get(id).copied()
+}
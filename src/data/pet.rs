@@ -0,0 +1,38 @@
+use phf::{Map, phf_map};
+
+use crate::data::{StatBuff as Buff, skill::*};
+use crate::data::skill_damage::{Recast, SkillDamage};
+use crate::models::damage::{DamageSize, DamageTime, DamageType};
+use crate::models::resource::PlayerAttributeType;
+
+/// A pet summoned by a skill. Pets attack the owner's target every `attack.frequency_ms`, scaling off the owner's stats.
+/// Attacks are logged under the skill which summoned the pet.
+#[derive(Debug, PartialEq)]
+pub struct PetData {
+    pub id: u32,
+    pub attack: &'static SkillDamage,
+    /// `None` for permanent pets, which stay until dismissed.
+    pub duration_ms: Option<u64>,
+}
+
+pub static UNSTABLE_FAMILIAR_ATTACK: SkillDamage = SkillDamage { id: SUMMON_UNSTABLE_FAMILIAR_ID, damage_type: DamageType::SHOCK, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0310, power_coefficient: 0.3258, duration_ms: 0, frequency_ms: 2000, snapshot: false, ground: false, recast: Recast::Overwrite, execute: None };
+pub static TWILIGHT_TORMENTER_ATTACK: SkillDamage = SkillDamage { id: SUMMON_TWILIGHT_TORMENTER_ID, damage_type: DamageType::MAGIC, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0450, power_coefficient: 0.4725, duration_ms: 0, frequency_ms: 2000, snapshot: false, ground: false, recast: Recast::Overwrite, execute: None };
+pub static FERAL_GUARDIAN_ATTACK: SkillDamage = SkillDamage { id: FERAL_GUARDIAN_ID, damage_type: DamageType::PHYSICAL, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0397, power_coefficient: 0.4170, duration_ms: 0, frequency_ms: 2000, snapshot: false, ground: false, recast: Recast::Overwrite, execute: None };
+pub static SKELETAL_MAGE_ATTACK: SkillDamage = SkillDamage { id: SKELETAL_MAGE_ID, damage_type: DamageType::SHOCK, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0375, power_coefficient: 0.3938, duration_ms: 0, frequency_ms: 2000, snapshot: false, ground: false, recast: Recast::Overwrite, execute: None };
+
+pub static UNSTABLE_FAMILIAR: PetData = PetData { id: SUMMON_UNSTABLE_FAMILIAR_ID, attack: &UNSTABLE_FAMILIAR_ATTACK, duration_ms: None };
+pub static TWILIGHT_TORMENTER: PetData = PetData { id: SUMMON_TWILIGHT_TORMENTER_ID, attack: &TWILIGHT_TORMENTER_ATTACK, duration_ms: None };
+pub static FERAL_GUARDIAN: PetData = PetData { id: FERAL_GUARDIAN_ID, attack: &FERAL_GUARDIAN_ATTACK, duration_ms: None };
+pub static SKELETAL_MAGE: PetData = PetData { id: SKELETAL_MAGE_ID, attack: &SKELETAL_MAGE_ATTACK, duration_ms: Some(20000) };
+
+pub static PET_BY_ID: Map<u32, &'static PetData> = phf_map! {
+    23304u32 => &UNSTABLE_FAMILIAR,
+    24636u32 => &TWILIGHT_TORMENTER,
+    85982u32 => &FERAL_GUARDIAN,
+    114317u32 => &SKELETAL_MAGE,
+};
+
+// pet damage done, added to the owner's damage done for pet attacks
+
+pub static PET_DAMAGE_DONE_BY_ID: Map<u32, &'static Buff> = phf_map! {
+};
@@ -0,0 +1,71 @@
+use phf::{Map, phf_map};
+
+use crate::data::major_minor::*;
+use crate::models::damage::DamageType;
+use crate::models::resource::PlayerAttributeType;
+
+pub const POISON_COOLDOWN_MS: u64 = 10000;
+pub const POISON_DURATION_MS: u64 = 10000;
+/// Largest stack of poisons that can be equipped in one slot.
+pub const POISON_STACK_MAXIMUM: u16 = 100;
+
+#[derive(Debug, PartialEq)]
+pub enum PoisonEffect {
+    Damage,
+    /// Deals damage and restores as much of the given resource to the caster.
+    Drain(PlayerAttributeType),
+    /// Applies a debuff to the target, such as Major Breach.
+    Debuff(u32),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Poison {
+    pub id: u32,
+    pub effect: PoisonEffect,
+    pub damage_type: DamageType,
+    /// CP160 damage of the proc. Debuff poisons deal no damage.
+    pub damage: f32,
+    pub duration_ms: u64,
+}
+
+// Crafted poisons are identified by their effect rather than the item, as the reagent combinations produce many item ids. Fake IDs.
+pub const RAVAGE_HEALTH_POISON_ID: u32 = 4003000;
+pub const DRAIN_HEALTH_POISON_ID: u32 = 4003001;
+pub const DRAIN_MAGICKA_POISON_ID: u32 = 4003002;
+pub const DRAIN_STAMINA_POISON_ID: u32 = 4003003;
+pub const BREACH_POISON_ID: u32 = 4003004;
+pub const DEFILE_POISON_ID: u32 = 4003005;
+pub const MAIM_POISON_ID: u32 = 4003006;
+pub const VULNERABILITY_POISON_ID: u32 = 4003007;
+pub const COWARDICE_POISON_ID: u32 = 4003008;
+pub const ENERVATION_POISON_ID: u32 = 4003009;
+pub const UNCERTAINTY_POISON_ID: u32 = 4003010;
+pub const CROWN_LETHAL_POISON_ID: u32 = 4003011;
+
+pub static RAVAGE_HEALTH_POISON: Poison = Poison { id: RAVAGE_HEALTH_POISON_ID, effect: PoisonEffect::Damage, damage_type: DamageType::POISON, damage: 2580.0, duration_ms: 0 };
+pub static CROWN_LETHAL_POISON: Poison = Poison { id: CROWN_LETHAL_POISON_ID, effect: PoisonEffect::Damage, damage_type: DamageType::POISON, damage: 3000.0, duration_ms: 0 };
+pub static DRAIN_HEALTH_POISON: Poison = Poison { id: DRAIN_HEALTH_POISON_ID, effect: PoisonEffect::Drain(PlayerAttributeType::Health), damage_type: DamageType::POISON, damage: 1380.0, duration_ms: 0 };
+pub static DRAIN_MAGICKA_POISON: Poison = Poison { id: DRAIN_MAGICKA_POISON_ID, effect: PoisonEffect::Drain(PlayerAttributeType::Magicka), damage_type: DamageType::POISON, damage: 1380.0, duration_ms: 0 };
+pub static DRAIN_STAMINA_POISON: Poison = Poison { id: DRAIN_STAMINA_POISON_ID, effect: PoisonEffect::Drain(PlayerAttributeType::Stamina), damage_type: DamageType::POISON, damage: 1380.0, duration_ms: 0 };
+pub static BREACH_POISON: Poison = Poison { id: BREACH_POISON_ID, effect: PoisonEffect::Debuff(BREACH_MAJOR_ID), damage_type: DamageType::POISON, damage: 0.0, duration_ms: POISON_DURATION_MS };
+pub static DEFILE_POISON: Poison = Poison { id: DEFILE_POISON_ID, effect: PoisonEffect::Debuff(DEFILE_MAJOR_ID), damage_type: DamageType::POISON, damage: 0.0, duration_ms: POISON_DURATION_MS };
+pub static MAIM_POISON: Poison = Poison { id: MAIM_POISON_ID, effect: PoisonEffect::Debuff(MAIM_MAJOR_ID), damage_type: DamageType::POISON, damage: 0.0, duration_ms: POISON_DURATION_MS };
+pub static VULNERABILITY_POISON: Poison = Poison { id: VULNERABILITY_POISON_ID, effect: PoisonEffect::Debuff(VULNERABILITY_MINOR_ID), damage_type: DamageType::POISON, damage: 0.0, duration_ms: POISON_DURATION_MS };
+pub static COWARDICE_POISON: Poison = Poison { id: COWARDICE_POISON_ID, effect: PoisonEffect::Debuff(COWARDICE_MAJOR_ID), damage_type: DamageType::POISON, damage: 0.0, duration_ms: POISON_DURATION_MS };
+pub static ENERVATION_POISON: Poison = Poison { id: ENERVATION_POISON_ID, effect: PoisonEffect::Debuff(ENERVATION_MINOR_ID), damage_type: DamageType::POISON, damage: 0.0, duration_ms: POISON_DURATION_MS };
+pub static UNCERTAINTY_POISON: Poison = Poison { id: UNCERTAINTY_POISON_ID, effect: PoisonEffect::Debuff(UNCERTAINTY_MINOR_ID), damage_type: DamageType::POISON, damage: 0.0, duration_ms: POISON_DURATION_MS };
+
+pub static POISONS_BY_ID: Map<u32, &'static Poison> = phf_map! {
+    4003000 => &RAVAGE_HEALTH_POISON,
+    4003001 => &DRAIN_HEALTH_POISON,
+    4003002 => &DRAIN_MAGICKA_POISON,
+    4003003 => &DRAIN_STAMINA_POISON,
+    4003004 => &BREACH_POISON,
+    4003005 => &DEFILE_POISON,
+    4003006 => &MAIM_POISON,
+    4003007 => &VULNERABILITY_POISON,
+    4003008 => &COWARDICE_POISON,
+    4003009 => &ENERVATION_POISON,
+    4003010 => &UNCERTAINTY_POISON,
+    4003011 => &CROWN_LETHAL_POISON,
+};
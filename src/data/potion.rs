@@ -0,0 +1,53 @@
+use phf::{Map, phf_map};
+
+use crate::data::armour::{PHYSICAL_RESIST_POTION_ID, SPELL_RESIST_POTION_ID};
+use crate::data::major_minor::*;
+
+pub const POTION_COOLDOWN_MS: u64 = 45000;
+/// Medicinal Use (Alchemy passive) increases the duration of potion effects by 30%..
+pub const MEDICINAL_USE_DURATION_MULTIPLIER: f32 = 0.3;
+
+#[derive(Debug, PartialEq)]
+pub struct Potion {
+    pub id: u32,
+    pub buffs: &'static [u32],
+    /// Health, Magicka, Stamina restored on use.
+    pub restore: (u32, u32, u32),
+    pub duration_ms: u64,
+}
+
+// Crafted potions are identified by their effects rather than the item, as the reagent combinations produce many item ids. Fake IDs.
+pub const ESSENCE_OF_SPELL_POWER_ID: u32 = 4004000;
+pub const ESSENCE_OF_WEAPON_POWER_ID: u32 = 4004001;
+pub const TRI_STAT_POTION_ID: u32 = 4004002;
+pub const SPELL_RESISTANCE_POTION_ID: u32 = 4004003;
+pub const PHYSICAL_RESISTANCE_POTION_ID: u32 = 4004004;
+pub const HEROISM_POTION_ID: u32 = 4004005;
+pub const CROWN_FORTIFYING_POTION_ID: u32 = 4004006;
+pub const CROWN_TRI_RESTORATION_POTION_ID: u32 = 4004007;
+
+/// CP150 crafted potion
+pub static ESSENCE_OF_SPELL_POWER: Potion = Potion { id: ESSENCE_OF_SPELL_POWER_ID, buffs: &[SORCERY_MAJOR_ID, PROPHECY_MAJOR_ID, INTELLECT_MAJOR_ID], restore: (0, 6048, 0), duration_ms: 36600 };
+/// CP150 crafted potion
+pub static ESSENCE_OF_WEAPON_POWER: Potion = Potion { id: ESSENCE_OF_WEAPON_POWER_ID, buffs: &[BRUTALITY_MAJOR_ID, SAVAGERY_MAJOR_ID, ENDURANCE_MAJOR_ID], restore: (0, 0, 6048), duration_ms: 36600 };
+/// CP150 crafted potion
+pub static TRI_STAT_POTION: Potion = Potion { id: TRI_STAT_POTION_ID, buffs: &[], restore: (6048, 6048, 6048), duration_ms: 0 };
+/// CP150 crafted potion
+pub static SPELL_RESISTANCE_POTION: Potion = Potion { id: SPELL_RESISTANCE_POTION_ID, buffs: &[SPELL_RESIST_POTION_ID], restore: (0, 6048, 0), duration_ms: 36600 };
+/// CP150 crafted potion
+pub static PHYSICAL_RESISTANCE_POTION: Potion = Potion { id: PHYSICAL_RESISTANCE_POTION_ID, buffs: &[PHYSICAL_RESIST_POTION_ID], restore: (0, 0, 6048), duration_ms: 36600 };
+/// CP150 crafted potion
+pub static HEROISM_POTION: Potion = Potion { id: HEROISM_POTION_ID, buffs: &[HEROISM_MAJOR_ID], restore: (0, 0, 6048), duration_ms: 36600 };
+pub static CROWN_FORTIFYING_POTION: Potion = Potion { id: CROWN_FORTIFYING_POTION_ID, buffs: &[FORTITUDE_MAJOR_ID, INTELLECT_MAJOR_ID, ENDURANCE_MAJOR_ID], restore: (5000, 5000, 5000), duration_ms: 23300 };
+pub static CROWN_TRI_RESTORATION_POTION: Potion = Potion { id: CROWN_TRI_RESTORATION_POTION_ID, buffs: &[], restore: (5000, 5000, 5000), duration_ms: 0 };
+
+pub static POTIONS_BY_ID: Map<u32, &'static Potion> = phf_map! {
+    4004000 => &ESSENCE_OF_SPELL_POWER,
+    4004001 => &ESSENCE_OF_WEAPON_POWER,
+    4004002 => &TRI_STAT_POTION,
+    4004003 => &SPELL_RESISTANCE_POTION,
+    4004004 => &PHYSICAL_RESISTANCE_POTION,
+    4004005 => &HEROISM_POTION,
+    4004006 => &CROWN_FORTIFYING_POTION,
+    4004007 => &CROWN_TRI_RESTORATION_POTION,
+};
@@ -17,6 +17,10 @@ pub enum SetBonusType {
     Penetration(Option<u32>),
     ReducePlayerDamageTaken(Option<u32>),
     CriticalResistance(Option<u32>),
+    /// Percentage of a blocked hit's damage negated.
+    BlockMitigation(Option<u32>),
+    /// Percentage off the cost of blocking.
+    BlockCost(Option<u32>),
 }
 
 impl SetBonusType {
@@ -42,6 +46,8 @@ impl SetBonusType {
             SetBonusType::CriticalResistance(o) => {
                 o.unwrap_or(SET_CRITICAL_RESISTANCE_DEFAULT)
             }
+            SetBonusType::BlockMitigation(o) => o.unwrap_or(SET_BLOCK_MITIGATION_DEFAULT),
+            SetBonusType::BlockCost(o) => o.unwrap_or(SET_BLOCK_COST_DEFAULT),
         }
     }
 
@@ -68,6 +74,13 @@ pub fn get_total_bonus(set_details: &ActiveSet, bonus: &SetBonusType) -> u32 {
     0
 }
 
+/// Whether `set_details` has enough pieces for any of the set's bonuses.
+pub fn has_bonus(set_details: &ActiveSet) -> bool {
+    SET_BONUSES.get(&set_details.set_id).is_some_and(|set_bonuses| {
+        set_bonuses.bonuses.iter().take(set_details.count as usize).any(|group| !group.is_empty())
+    })
+}
+
 // todo: fix scaling
 pub const SET_HEALTH_DEFAULT: u32 = 1206;
 pub const SET_STAMINA_DEFAULT: u32 = 1096;
@@ -85,6 +98,8 @@ pub const SET_STAMINA_RECOVERY_DEFAULT: u32 = 129;
 pub const SET_HEALTH_RECOVERY_DEFAULT: u32 = 129;
 pub const SET_HEALING_DONE_DEFAULT: u32 = 4;
 pub const SET_HEALING_TAKEN_DEFAULT: u32 = 4;
+pub const SET_BLOCK_MITIGATION_DEFAULT: u32 = 8;
+pub const SET_BLOCK_COST_DEFAULT: u32 = 10;
 
 #[cfg(test)]
 mod tests {
@@ -1356,6 +1371,7 @@ static BLACK_GLOVE_GROUNDING: Set = Set {
         &[SetBonusType::HealingTaken(None)],
         &[SetBonusType::Health(None)],
         &[SetBonusType::Health(None)],
+        &[SetBonusType::BlockMitigation(None)],
     ],
 };
 
@@ -2447,6 +2463,7 @@ static FULL_BELLY_BARRICADE: Set = Set {
         &[SetBonusType::Health(None)],
         &[SetBonusType::Stamina(None)],
         &[SetBonusType::Stamina(None)],
+        &[SetBonusType::BlockMitigation(Some(10)), SetBonusType::BlockCost(None)],
     ],
 };
 
@@ -0,0 +1,74 @@
+use phf::{Map, phf_map};
+
+use crate::data::skill::*;
+use crate::models::damage::{DamageSize, DamageTime, DamageType};
+use crate::models::resource::PlayerAttributeType;
+
+/// Most area of effect skills hit at most this many enemies.
+pub const AOE_TARGET_CAP: usize = 6;
+
+/// What happens when a damage over time effect is recast on a target which still has it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Recast {
+    /// The old instance is removed and the new one starts ticking from the cast.
+    Overwrite,
+    /// The duration is extended to a full duration without resetting the tick timer.
+    Refresh,
+}
+
+/// Bonus damage against enemies below a fraction of their max health.
+#[derive(Debug, PartialEq)]
+pub struct Execute {
+    pub threshold: f32,
+    /// Fraction of damage added below the threshold.
+    pub bonus: f32,
+}
+
+/// Damage of an ability. Tooltip damage, per tick for damage over time, is `resource_coefficient * max resource + power_coefficient * power`.
+#[derive(Debug, PartialEq)]
+pub struct SkillDamage {
+    pub id: u32,
+    pub damage_type: DamageType,
+    pub size: DamageSize,
+    pub time: DamageTime,
+    pub resource: PlayerAttributeType,
+    pub resource_coefficient: f32,
+    pub power_coefficient: f32,
+    pub duration_ms: u64,
+    pub frequency_ms: u64,
+    /// Whether ticks use the caster's stats at the cast rather than at each tick.
+    pub snapshot: bool,
+    /// Ground effects tick as soon as they are placed.
+    pub ground: bool,
+    pub recast: Recast,
+    pub execute: Option<Execute>,
+}
+
+pub static CRYSTAL_FRAGMENTS: SkillDamage = SkillDamage { id: CRYSTAL_FRAGMENTS_ID, damage_type: DamageType::MAGIC, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.1035, power_coefficient: 1.0868, duration_ms: 0, frequency_ms: 0, snapshot: false, ground: false, recast: Recast::Overwrite, execute: None };
+pub static CRYSTAL_SHARD: SkillDamage = SkillDamage { id: CRYSTAL_SHARD_ID, damage_type: DamageType::MAGIC, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0900, power_coefficient: 0.9450, duration_ms: 0, frequency_ms: 0, snapshot: false, ground: false, recast: Recast::Overwrite, execute: None };
+pub static PUNCTURING_STRIKES: SkillDamage = SkillDamage { id: PUNCTURING_STRIKES_ID, damage_type: DamageType::PHYSICAL, size: DamageSize::MULTITARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Stamina, resource_coefficient: 0.0280, power_coefficient: 0.2940, duration_ms: 0, frequency_ms: 0, snapshot: false, ground: false, recast: Recast::Overwrite, execute: None };
+pub static PUNCTURING_SWEEPS: SkillDamage = SkillDamage { id: PUNCTURING_SWEEPS_ID, damage_type: DamageType::PHYSICAL, size: DamageSize::MULTITARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Stamina, resource_coefficient: 0.0392, power_coefficient: 0.4116, duration_ms: 0, frequency_ms: 0, snapshot: false, ground: false, recast: Recast::Overwrite, execute: None };
+pub static LIQUID_LIGHTNING: SkillDamage = SkillDamage { id: LIQUID_LIGHTNING_ID, damage_type: DamageType::SHOCK, size: DamageSize::MULTITARGET, time: DamageTime::DOT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0105, power_coefficient: 0.1103, duration_ms: 10000, frequency_ms: 1000, snapshot: false, ground: true, recast: Recast::Overwrite, execute: None };
+pub static VAMPIRES_BANE: SkillDamage = SkillDamage { id: VAMPIRES_BANE_ID, damage_type: DamageType::MAGIC, size: DamageSize::SINGLETARGET, time: DamageTime::DOT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0173, power_coefficient: 0.1816, duration_ms: 30000, frequency_ms: 2000, snapshot: true, ground: false, recast: Recast::Refresh, execute: None };
+pub static VENOMOUS_CLAW: SkillDamage = SkillDamage { id: VENOMOUS_CLAW_ID, damage_type: DamageType::POISON, size: DamageSize::SINGLETARGET, time: DamageTime::DOT, resource: PlayerAttributeType::Stamina, resource_coefficient: 0.0131, power_coefficient: 0.1376, duration_ms: 24000, frequency_ms: 2000, snapshot: true, ground: false, recast: Recast::Refresh, execute: None };
+pub static TWISTING_PATH: SkillDamage = SkillDamage { id: TWISTING_PATH_ID, damage_type: DamageType::MAGIC, size: DamageSize::MULTITARGET, time: DamageTime::DOT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0094, power_coefficient: 0.0990, duration_ms: 10000, frequency_ms: 1000, snapshot: false, ground: true, recast: Recast::Overwrite, execute: None };
+pub static BARBED_TRAP: SkillDamage = SkillDamage { id: BARBED_TRAP_ID, damage_type: DamageType::BLEED, size: DamageSize::SINGLETARGET, time: DamageTime::DOT, resource: PlayerAttributeType::Stamina, resource_coefficient: 0.0123, power_coefficient: 0.1290, duration_ms: 20000, frequency_ms: 2000, snapshot: true, ground: false, recast: Recast::Overwrite, execute: None };
+
+pub static MAGES_WRATH: SkillDamage = SkillDamage { id: MAGES_WRATH_ID, damage_type: DamageType::SHOCK, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0840, power_coefficient: 0.8820, duration_ms: 0, frequency_ms: 0, snapshot: false, ground: false, recast: Recast::Overwrite, execute: Some(Execute { threshold: 0.2, bonus: 1.0 }) };
+pub static RADIANT_DESTRUCTION: SkillDamage = SkillDamage { id: RADIANT_DESTRUCTION_ID, damage_type: DamageType::MAGIC, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Magicka, resource_coefficient: 0.0330, power_coefficient: 0.3465, duration_ms: 0, frequency_ms: 0, snapshot: false, ground: false, recast: Recast::Overwrite, execute: Some(Execute { threshold: 0.5, bonus: 4.8 }) };
+pub static KILLERS_BLADE: SkillDamage = SkillDamage { id: KILLERS_BLADE_ID, damage_type: DamageType::PHYSICAL, size: DamageSize::SINGLETARGET, time: DamageTime::INSTANT, resource: PlayerAttributeType::Stamina, resource_coefficient: 0.0800, power_coefficient: 0.8400, duration_ms: 0, frequency_ms: 0, snapshot: false, ground: false, recast: Recast::Overwrite, execute: Some(Execute { threshold: 0.5, bonus: 4.0 }) };
+
+pub static SKILL_DAMAGE_BY_ID: Map<u32, &'static SkillDamage> = phf_map! {
+    46324u32 => &CRYSTAL_FRAGMENTS,
+    43714u32 => &CRYSTAL_SHARD,
+    26144u32 => &PUNCTURING_STRIKES,
+    26797u32 => &PUNCTURING_SWEEPS,
+    23200u32 => &LIQUID_LIGHTNING,
+    21729u32 => &VAMPIRES_BANE,
+    20668u32 => &VENOMOUS_CLAW,
+    46049u32 => &TWISTING_PATH,
+    40382u32 => &BARBED_TRAP,
+    19123u32 => &MAGES_WRATH,
+    34843u32 => &KILLERS_BLADE,
+    63029u32 => &RADIANT_DESTRUCTION,
+};
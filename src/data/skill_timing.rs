@@ -0,0 +1,46 @@
+use phf::{Map, phf_map};
+
+use crate::data::major_minor::*;
+use crate::data::skill::*;
+use crate::data::skill_damage::SKILL_DAMAGE_BY_ID;
+use crate::models::damage::DamageTime;
+
+/// Each synergy can only be activated this often by the same player.
+pub const SYNERGY_COOLDOWN_MS: u64 = 20000;
+
+pub const COMBUSTION_SYNERGY_ID: u32 = 63507;
+pub const BLESSED_SHARDS_SYNERGY_ID: u32 = 26832;
+
+/// Duration and cooldown of an ability, as from `GetAbilityDuration` and `GetAbilityCooldown`. Damage over time durations are in `SkillDamage`.
+#[derive(Debug, PartialEq)]
+pub struct SkillTiming {
+    pub id: u32,
+    pub duration_ms: u64,
+    pub cooldown_ms: u64,
+    /// Applied to the caster for the duration.
+    pub buffs: &'static [u32],
+}
+
+pub static SURGE: SkillTiming = SkillTiming { id: SURGE_ID, duration_ms: 33000, cooldown_ms: 0, buffs: &[BRUTALITY_MAJOR_ID, SORCERY_MAJOR_ID] };
+pub static CRITICAL_SURGE: SkillTiming = SkillTiming { id: CRITICAL_SURGE_ID, duration_ms: 33000, cooldown_ms: 0, buffs: &[BRUTALITY_MAJOR_ID, SORCERY_MAJOR_ID] };
+pub static POWER_SURGE: SkillTiming = SkillTiming { id: POWER_SURGE_ID, duration_ms: 33000, cooldown_ms: 0, buffs: &[BRUTALITY_MAJOR_ID, SORCERY_MAJOR_ID] };
+pub static COMBUSTION_SYNERGY: SkillTiming = SkillTiming { id: COMBUSTION_SYNERGY_ID, duration_ms: 0, cooldown_ms: SYNERGY_COOLDOWN_MS, buffs: &[] };
+pub static BLESSED_SHARDS_SYNERGY: SkillTiming = SkillTiming { id: BLESSED_SHARDS_SYNERGY_ID, duration_ms: 0, cooldown_ms: SYNERGY_COOLDOWN_MS, buffs: &[] };
+
+pub static SKILL_TIMING_BY_ID: Map<u32, &'static SkillTiming> = phf_map! {
+    23670u32 => &SURGE,
+    23678u32 => &CRITICAL_SURGE,
+    23674u32 => &POWER_SURGE,
+    63507u32 => &COMBUSTION_SYNERGY,
+    26832u32 => &BLESSED_SHARDS_SYNERGY,
+};
+
+/// How long what `skill_id` applies lasts, from its timing or from its damage over time. `None` for abilities without a duration.
+pub fn get_skill_duration_ms(skill_id: u32) -> Option<u64> {
+    if let Some(timing) = SKILL_TIMING_BY_ID.get(&skill_id) {
+        return Some(timing.duration_ms).filter(|duration_ms| *duration_ms > 0);
+    }
+    SKILL_DAMAGE_BY_ID.get(&skill_id)
+        .filter(|skill| skill.time == DamageTime::DOT)
+        .map(|skill| skill.duration_ms)
+}
@@ -0,0 +1,80 @@
+use crate::data::major_minor::*;
+use crate::models::damage::DamageType;
+
+/// Chance for a damaging hit to apply the status effect of its damage type, before Charged.
+pub const STATUS_EFFECT_CHANCE_DEFAULT: f32 = 0.1;
+/// Damage from elemental weapon glyphs is twice as likely to apply a status effect.
+pub const STATUS_EFFECT_CHANCE_ENCHANT: f32 = 0.2;
+
+pub const BURNING_ID: u32 = 18084;
+pub const CHILLED_ID: u32 = 95136;
+pub const CONCUSSED_ID: u32 = 95134;
+pub const POISONED_ID: u32 = 21929;
+pub const DISEASED_ID: u32 = 178127;
+pub const HEMORRHAGING_ID: u32 = 148801;
+pub const SUNDERED_ID: u32 = 178123;
+pub const OVERCHARGED_ID: u32 = 178118;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusEffect {
+    Burning,
+    Chilled,
+    Concussed,
+    Poisoned,
+    Diseased,
+    Hemorrhaging,
+    Sundered,
+    Overcharged,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StatusEffectData {
+    pub id: u32,
+    pub effect: StatusEffect,
+    pub damage_type: DamageType,
+    pub duration_ms: u64,
+    /// CP160 damage of each tick, for the status effects which deal damage.
+    pub tick_damage: Option<f32>,
+    pub tick_interval_ms: u64,
+    /// Applied alongside the status effect for its duration.
+    pub debuffs: &'static [u32],
+}
+
+pub static BURNING: StatusEffectData = StatusEffectData { id: BURNING_ID, effect: StatusEffect::Burning, damage_type: DamageType::FIRE, duration_ms: 4000, tick_damage: Some(1100.0), tick_interval_ms: 2000, debuffs: &[] };
+pub static CHILLED: StatusEffectData = StatusEffectData { id: CHILLED_ID, effect: StatusEffect::Chilled, damage_type: DamageType::COLD, duration_ms: 4000, tick_damage: None, tick_interval_ms: 0, debuffs: &[BRITTLE_MINOR_ID] };
+pub static CONCUSSED: StatusEffectData = StatusEffectData { id: CONCUSSED_ID, effect: StatusEffect::Concussed, damage_type: DamageType::SHOCK, duration_ms: 4000, tick_damage: None, tick_interval_ms: 0, debuffs: &[VULNERABILITY_MINOR_ID] };
+pub static POISONED: StatusEffectData = StatusEffectData { id: POISONED_ID, effect: StatusEffect::Poisoned, damage_type: DamageType::POISON, duration_ms: 4000, tick_damage: Some(1100.0), tick_interval_ms: 2000, debuffs: &[] };
+pub static DISEASED: StatusEffectData = StatusEffectData { id: DISEASED_ID, effect: StatusEffect::Diseased, damage_type: DamageType::DISEASE, duration_ms: 4000, tick_damage: None, tick_interval_ms: 0, debuffs: &[DEFILE_MINOR_ID] };
+pub static HEMORRHAGING: StatusEffectData = StatusEffectData { id: HEMORRHAGING_ID, effect: StatusEffect::Hemorrhaging, damage_type: DamageType::BLEED, duration_ms: 4000, tick_damage: Some(1100.0), tick_interval_ms: 2000, debuffs: &[MANGLE_MINOR_ID] };
+pub static SUNDERED: StatusEffectData = StatusEffectData { id: SUNDERED_ID, effect: StatusEffect::Sundered, damage_type: DamageType::PHYSICAL, duration_ms: 4000, tick_damage: None, tick_interval_ms: 0, debuffs: &[BREACH_MINOR_ID] };
+pub static OVERCHARGED: StatusEffectData = StatusEffectData { id: OVERCHARGED_ID, effect: StatusEffect::Overcharged, damage_type: DamageType::MAGIC, duration_ms: 4000, tick_damage: None, tick_interval_ms: 0, debuffs: &[MAGICKASTEAL_MINOR_ID] };
+
+/// Oblivion damage has no status effect.
+pub fn get_status_effect(damage_type: &DamageType) -> Option<&'static StatusEffectData> {
+    Some(match damage_type {
+        DamageType::FIRE => &BURNING,
+        DamageType::COLD => &CHILLED,
+        DamageType::SHOCK => &CONCUSSED,
+        DamageType::POISON => &POISONED,
+        DamageType::DISEASE => &DISEASED,
+        DamageType::BLEED => &HEMORRHAGING,
+        DamageType::PHYSICAL => &SUNDERED,
+        DamageType::MAGIC => &OVERCHARGED,
+        DamageType::OBLIVION => return None,
+    })
+}
+
+impl StatusEffect {
+    pub fn get_data(&self) -> &'static StatusEffectData {
+        match self {
+            StatusEffect::Burning => &BURNING,
+            StatusEffect::Chilled => &CHILLED,
+            StatusEffect::Concussed => &CONCUSSED,
+            StatusEffect::Poisoned => &POISONED,
+            StatusEffect::Diseased => &DISEASED,
+            StatusEffect::Hemorrhaging => &HEMORRHAGING,
+            StatusEffect::Sundered => &SUNDERED,
+            StatusEffect::Overcharged => &OVERCHARGED,
+        }
+    }
+}
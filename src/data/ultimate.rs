@@ -0,0 +1,59 @@
+use phf::{Map, phf_map};
+
+use crate::data::{StatBuff as Buff, major_minor::*, skill::*};
+
+pub const ULTIMATE_MAX: u32 = 500;
+pub const ULTIMATE_PER_LIGHT_ATTACK: u32 = 3;
+pub const ULTIMATE_PER_HEAVY_ATTACK: u32 = 3;
+/// Heroism grants its ultimate every interval while it is active.
+pub const ULTIMATE_GENERATION_INTERVAL_MS: u64 = 1500;
+
+// ultimate generated every interval
+pub static HEROISM_MAJOR: Buff = Buff { id: HEROISM_MAJOR_ID, value: 3f64, value_per_stack: 0f64};
+pub static HEROISM_MINOR: Buff = Buff { id: HEROISM_MINOR_ID, value: 1f64, value_per_stack: 0f64};
+
+pub static ULTIMATE_GENERATION_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    61709u32 => &HEROISM_MAJOR,
+    61708u32 => &HEROISM_MINOR,
+};
+
+// ultimate gained on drinking a potion
+pub static CATALYST: Buff = Buff { id: CATALYST_ID, value: 20f64, value_per_stack: 0f64};
+
+pub static ULTIMATE_ON_POTION_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    45135u32 => &CATALYST,
+};
+
+// ultimate cost reduction
+pub static POWER_STONE: Buff = Buff { id: POWER_STONE_ID, value: 15f64, value_per_stack: 0f64};
+
+pub static ULTIMATE_COST_REDUCTION_BY_ID: Map<u32, &'static Buff> = phf_map! {
+    45196u32 => &POWER_STONE,
+};
+
+/// Base ultimate cost of ultimate abilities.
+pub static ULTIMATE_COST_BY_ID: Map<u32, u32> = phf_map! {
+    23634u32 => 200, // Summon Storm Atronach
+    23492u32 => 200, // Greater Storm Atronach
+    23495u32 => 200, // Summon Charged Atronach
+    24785u32 => 250, // Overload
+    24806u32 => 250, // Power Overload
+    24804u32 => 250, // Energy Overload
+    21752u32 => 250, // Nova
+    21755u32 => 250, // Solar Prison
+    21758u32 => 250, // Solar Disturbance
+    28988u32 => 250, // Dragonknight Standard
+    32958u32 => 250, // Shifting Standard
+    32947u32 => 250, // Standard of Might
+    29012u32 => 250, // Dragon Leap
+    32719u32 => 250, // Take Flight
+    32715u32 => 250, // Ferocious Leap
+    33398u32 => 120, // Death Stroke
+    113105u32 => 120, // Incapacitating Strike
+    36514u32 => 120, // Soul Harvest
+    86109u32 => 250, // Sleet Storm
+    86113u32 => 250, // Northern Storm
+    86117u32 => 250, // Permafrost
+    35713u32 => 125, // Dawnbreaker
+    40161u32 => 125, // Flawless Dawnbreaker
+};
@@ -1,4 +1,5 @@
 pub mod player;
+pub mod warning;
 pub mod world;
 
 type ID = u32;
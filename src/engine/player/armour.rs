@@ -5,16 +5,25 @@ use crate::data::critical_damage::TWIN_BLADE_AND_BLUNT_ID;
 use crate::data::item_type::{EnchantType, GearTrait, ItemType, is_two_handed_weapon_option};
 use crate::data::sets::{SetBonusType, get_total_bonus};
 use crate::data::skill::{FROZEN_ARMOUR_ID, SPLINTERED_SECRETS_ID, SkillLine};
+use crate::data::status_effect::{BURNING_ID, CHILLED_ID, CONCUSSED_ID};
 use crate::data::enchant::*;
 use crate::data::traits::get_weapon_sharpened_value;
 use crate::engine::{ID, STACKS};
+use crate::engine::player::breakdown::{Operation, StatBreakdown, get_buff_rating, get_buff_value};
 use crate::models::armour::{Armour as ArmourModel, Penetration as PenetrationModel};
 use crate::models::damage::DamageType;
 use crate::models::player::Player;
 
+#[derive(Clone)]
 pub struct Armour {
     pub sources: HashMap<ID, STACKS>,
     player_armour: u32,
+    /// Armour value of the active gear and set bonuses.
+    gear_armour: u32,
+    /// Stacks of the Fortified CP, assumed for players wearing heavy armour.
+    fortified_stacks: STACKS,
+    /// Resistance glyphs on the active gear, by the damage type they resist.
+    glyph_resistance: HashMap<DamageType, u32>,
     bleed: ArmourModel,
     cold: ArmourModel,
     disease: ArmourModel,
@@ -33,6 +42,9 @@ impl Armour {
         Self {
             sources: HashMap::new(),
             player_armour: 0,
+            gear_armour: 0,
+            fortified_stacks: 0,
+            glyph_resistance: HashMap::new(),
             bleed: ArmourModel::default(),
             cold: ArmourModel::default(),
             disease: ArmourModel::default(),
@@ -76,7 +88,7 @@ impl Armour {
         self.spell.add_to_additive(self.player_armour.clone());
         for (id, stacks) in &self.sources {
             if let Some(buff) = ARMOUR_ALL_BY_ID.get(&id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = get_buff_rating(buff, *stacks);
                 self.bleed.add_to_additive(value.clone());
                 self.cold.add_to_additive(value.clone());
                 self.disease.add_to_additive(value.clone());
@@ -89,7 +101,7 @@ impl Armour {
             }
 
             if let Some(buff) = SPELL_RESISTANCE_BY_ID.get(&id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = get_buff_rating(buff, *stacks);
                 self.cold.add_to_additive(value.clone());
                 self.fire.add_to_additive(value.clone());
                 self.magic.add_to_additive(value.clone());
@@ -98,7 +110,7 @@ impl Armour {
             }
 
             if let Some(buff) = PHYSICAL_RESISTANCE_BY_ID.get(&id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = get_buff_rating(buff, *stacks);
                 self.bleed.add_to_additive(value.clone());
                 self.disease.add_to_additive(value.clone());
                 self.physical.add_to_additive(value.clone());
@@ -106,12 +118,12 @@ impl Armour {
             }
 
             if let Some(buff) = FROST_RESISTANCE_BY_ID.get(&id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = get_buff_rating(buff, *stacks);
                 self.cold.add_to_additive(value.clone());
             }
 
             if let Some(buff) = POISON_DISEASE_RESISTANCE_BY_ID.get(&id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = get_buff_rating(buff, *stacks);
                 self.poison.add_to_additive(value.clone());
                 self.disease.add_to_additive(value.clone());
             }
@@ -133,8 +145,9 @@ impl Armour {
         self.is_dirty = true;
     }
 
+    /// Burning, Chilled and Concussed are sources too, as they double Spell Attunement.
     pub fn is_valid_source(id: &ID) -> bool {
-        ARMOUR_ALL_BY_ID.get(id).is_some() || SPELL_RESISTANCE_BY_ID.get(id).is_some() || PHYSICAL_RESISTANCE_BY_ID.get(id).is_some() || FROST_RESISTANCE_BY_ID.get(id).is_some() || POISON_DISEASE_RESISTANCE_BY_ID.get(id).is_some()
+        ARMOUR_ALL_BY_ID.get(id).is_some() || SPELL_RESISTANCE_BY_ID.get(id).is_some() || PHYSICAL_RESISTANCE_BY_ID.get(id).is_some() || FROST_RESISTANCE_BY_ID.get(id).is_some() || POISON_DISEASE_RESISTANCE_BY_ID.get(id).is_some() || [BURNING_ID, CHILLED_ID, CONCUSSED_ID].contains(id)
     }
 
     pub fn calculate(&self, damage_type: &DamageType) -> u32 {
@@ -151,6 +164,36 @@ impl Armour {
         }
     }
 
+    /// Breakdown of the resistance against `damage_type`. Gear includes the armour value, set bonuses and resistance glyphs.
+    pub fn breakdown(&self, damage_type: &DamageType) -> StatBreakdown {
+        let mut breakdown = StatBreakdown::new();
+        let total = self.calculate(damage_type) as f64;
+        if *damage_type == DamageType::OBLIVION {
+            return breakdown.finish(total, total);
+        }
+        let is_spell = matches!(damage_type, DamageType::COLD | DamageType::FIRE | DamageType::MAGIC | DamageType::SHOCK);
+        for (id, stacks) in &self.sources {
+            let maps = [
+                Some(&ARMOUR_ALL_BY_ID),
+                is_spell.then_some(&SPELL_RESISTANCE_BY_ID),
+                (!is_spell).then_some(&PHYSICAL_RESISTANCE_BY_ID),
+                (*damage_type == DamageType::COLD).then_some(&FROST_RESISTANCE_BY_ID),
+                matches!(damage_type, DamageType::POISON | DamageType::DISEASE).then_some(&POISON_DISEASE_RESISTANCE_BY_ID),
+            ];
+            for map in maps.into_iter().flatten() {
+                if let Some(buff) = map.get(id) {
+                    breakdown.add_buff(*id, *stacks, get_buff_rating(buff, *stacks) as f64, Operation::Additive);
+                }
+            }
+        }
+        if self.fortified_stacks > 0 {
+            breakdown.add_buff(FORTIFIED.id, self.fortified_stacks, get_buff_value(&FORTIFIED, self.fortified_stacks).round(), Operation::Additive);
+        }
+        let glyph = self.glyph_resistance.get(damage_type).copied().unwrap_or(0);
+        breakdown.add_gear((self.gear_armour + glyph) as f64);
+        breakdown.finish(total, total)
+    }
+
     pub fn update_from_player(&mut self, player: &Player) {
         self.reset_all();
         self.sources.clear();
@@ -166,39 +209,61 @@ impl Armour {
                 self.add_source(*id, Some(*stacks))
             }
         }
+        let afflicted = [BURNING_ID, CHILLED_ID, CONCUSSED_ID].iter().any(|id| player.has_buff(id));
+        if afflicted && let Some(stacks) = self.sources.get_mut(&SPELL_ATTUNEMENT.id) {
+            *stacks *= 2;
+        }
 
         if heavy > 0 {self.add_source(45533, Some(heavy))};
         if light > 0 {self.add_source(45559, Some(light))};
         if warden > 0 {self.add_source(FROZEN_ARMOUR_ID, Some(warden))};
+        self.fortified_stacks = 0;
         if heavy >= 4 {
             if ice_staves_shields > 0 {self.add_source(64079, Some(1))}; // Assume players have this because it is shown for only the person logging
-            player_armour += (34.62f32 * 50.0).round() as u32; // Assume players wearing heavy armour (tanks) always have this CP because it is never shown on logs so there is no way to know until reverse engineering the damage taken numbers.
+            self.fortified_stacks = 50; // Assume players wearing heavy armour (tanks) always have this CP because it is never shown on logs so there is no way to know until reverse engineering the damage taken numbers.
         }
         for set in player.get_active_sets_counts() {
             player_armour += get_total_bonus(&set, &SetBonusType::Armour(None));
         }
 
-        self.player_armour = player_armour;
+        self.gear_armour = player_armour;
+        self.player_armour = player_armour + get_buff_value(&FORTIFIED, self.fortified_stacks).round() as u32;
 
         self.refresh();
 
+        self.glyph_resistance.clear();
         for gear in player.get_active_gear() {
             if let Some(enchant) = &gear.enchant {
+                let multi = gear.get_enchant_multiplier();
                 match enchant.glyph {
-                    EnchantType::FrostResistance => self.cold.add_to_additive(get_enchant_jewellery_increase_frost_resistance(&enchant.effective_level, &enchant.quality) as u32),
-                    EnchantType::FireResistance => self.fire.add_to_additive(get_enchant_jewellery_increase_fire_resistance(&enchant.effective_level, &enchant.quality) as u32),
-                    EnchantType::ShockResistance => self.shock.add_to_additive(get_enchant_jewellery_increase_shock_resistance(&enchant.effective_level, &enchant.quality) as u32),
-                    EnchantType::PoisonResistance => self.poison.add_to_additive(get_enchant_jewellery_increase_poison_resistance(&enchant.effective_level, &enchant.quality) as u32),
-                    EnchantType::DiseaseResistance => self.disease.add_to_additive(get_enchant_jewellery_increase_disease_resistance(&enchant.effective_level, &enchant.quality) as u32),
-                    EnchantType::PhysicalResistance => self.physical.add_to_additive(get_enchant_jewellery_increase_physical_resistance(&enchant.effective_level, &enchant.quality) as u32),
-                    EnchantType::SpellResistance => self.spell.add_to_additive(get_enchant_jewellery_increase_spell_resistance(&enchant.effective_level, &enchant.quality) as u32),
+                    EnchantType::FrostResistance => self.add_glyph_resistance(DamageType::COLD, (multi * get_enchant_jewellery_increase_frost_resistance(&enchant.effective_level, &enchant.quality)) as u32),
+                    EnchantType::FireResistance => self.add_glyph_resistance(DamageType::FIRE, (multi * get_enchant_jewellery_increase_fire_resistance(&enchant.effective_level, &enchant.quality)) as u32),
+                    EnchantType::ShockResistance => self.add_glyph_resistance(DamageType::SHOCK, (multi * get_enchant_jewellery_increase_shock_resistance(&enchant.effective_level, &enchant.quality)) as u32),
+                    EnchantType::PoisonResistance => self.add_glyph_resistance(DamageType::POISON, (multi * get_enchant_jewellery_increase_poison_resistance(&enchant.effective_level, &enchant.quality)) as u32),
+                    EnchantType::DiseaseResistance => self.add_glyph_resistance(DamageType::DISEASE, (multi * get_enchant_jewellery_increase_disease_resistance(&enchant.effective_level, &enchant.quality)) as u32),
+                    EnchantType::PhysicalResistance => self.add_glyph_resistance(DamageType::PHYSICAL, (multi * get_enchant_jewellery_increase_physical_resistance(&enchant.effective_level, &enchant.quality)) as u32),
+                    EnchantType::SpellResistance => self.spell.add_to_additive((multi * get_enchant_jewellery_increase_spell_resistance(&enchant.effective_level, &enchant.quality)) as u32),
                     _ => {},
                 }
             }
         }
     }
+
+    fn add_glyph_resistance(&mut self, damage_type: DamageType, value: u32) {
+        *self.glyph_resistance.entry(damage_type).or_default() += value;
+        match damage_type {
+            DamageType::COLD => self.cold.add_to_additive(value),
+            DamageType::FIRE => self.fire.add_to_additive(value),
+            DamageType::SHOCK => self.shock.add_to_additive(value),
+            DamageType::POISON => self.poison.add_to_additive(value),
+            DamageType::DISEASE => self.disease.add_to_additive(value),
+            DamageType::PHYSICAL => self.physical.add_to_additive(value),
+            _ => {},
+        }
+    }
 }
 
+#[derive(Clone)]
 pub struct Penetration {
     pub sources: HashMap<ID, STACKS>,
     physical: PenetrationModel,
@@ -237,7 +302,7 @@ impl Penetration {
         self.reset_all();
         for (id, stacks) in &self.sources {
             if let Some(buff) = PENETRATION_ADDITIVE.get(&id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = get_buff_rating(buff, *stacks);
                 self.physical.add_to_additive(value);
                 self.spell.add_to_additive(value);
             }
@@ -261,6 +326,18 @@ impl Penetration {
         self.physical.calculate().max(self.spell.calculate())
     }
 
+    pub fn breakdown(&self) -> StatBreakdown {
+        let mut breakdown = StatBreakdown::new();
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = PENETRATION_ADDITIVE.get(id) {
+                breakdown.add_buff(*id, *stacks, get_buff_rating(buff, *stacks) as f64, Operation::Additive);
+            }
+        }
+        breakdown.add_gear(self.gear_source as f64);
+        let total = self.calculate() as f64;
+        breakdown.finish(total, total)
+    }
+
     pub fn update_from_player(&mut self, player: &Player) {
         self.reset_all();
         self.sources.clear();
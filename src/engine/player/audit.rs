@@ -0,0 +1,105 @@
+use crate::data::names::get_name;
+use crate::engine::ID;
+use crate::engine::player::character::Character;
+use crate::engine::world::event::UnitId;
+use crate::engine::world::target::Target;
+use crate::models::damage::DamageType;
+use crate::models::damage_taken::Resistance;
+
+const RESISTED_DAMAGE_TYPES: [DamageType; 8] = [
+    DamageType::BLEED,
+    DamageType::COLD,
+    DamageType::DISEASE,
+    DamageType::FIRE,
+    DamageType::MAGIC,
+    DamageType::PHYSICAL,
+    DamageType::POISON,
+    DamageType::SHOCK,
+];
+
+/// A stat or buff which is partly or entirely wasted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditFlag {
+    /// Critical damage percent above the cap.
+    CriticalDamageOvercap { wasted: u16 },
+    /// Penetration above the target's armour after its debuffs.
+    PenetrationOvercap { wasted: u32 },
+    /// Resistance above what reaches the mitigation cap.
+    ResistanceOvercap { damage_type: DamageType, wasted: u32 },
+    /// A unique buff applied by more than one source, of which only one counts.
+    DuplicateBuff { id: ID, name: &'static str, sources: Vec<UnitId>, wasted: usize },
+}
+
+/// Every flag for `character` attacking `target`.
+pub fn audit(character: &Character, target: &Target) -> Vec<AuditFlag> {
+    let mut flags = Vec::new();
+
+    let critical_damage = character.get_critical_damage_done_breakdown();
+    if let Some(uncapped) = critical_damage.capped_from {
+        flags.push(AuditFlag::CriticalDamageOvercap { wasted: (uncapped - critical_damage.total) as u16 });
+    }
+
+    let wasted_penetration = character.get_penetration().saturating_sub(target.get_armour());
+    if wasted_penetration > 0 {
+        flags.push(AuditFlag::PenetrationOvercap { wasted: wasted_penetration });
+    }
+
+    for damage_type in RESISTED_DAMAGE_TYPES {
+        let mut resistance = Resistance::new();
+        resistance.add_to_additive(character.get_armour(&damage_type));
+        let wasted = resistance.get_overcap();
+        if wasted > 0 {
+            flags.push(AuditFlag::ResistanceOvercap { damage_type, wasted });
+        }
+    }
+
+    let mut buffs: Vec<&ID> = character.get_buffs().keys().collect();
+    buffs.sort();
+    for id in buffs {
+        let mut sources = character.get_buff_sources(id);
+        if sources.len() > 1 {
+            sources.sort();
+            flags.push(AuditFlag::DuplicateBuff { id: *id, name: get_name(id).unwrap_or("Unknown"), wasted: sources.len() - 1, sources });
+        }
+    }
+
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::critical_damage::{ADVANCED_SPECIES_ID, FELINE_AMBUSH_ID, HEMORRHAGE_PASSIVE_ID, PIERCING_SPEAR_ID, SUL_XAN_SOULBOUND};
+    use crate::data::major_minor::{FORCE_MAJOR_ID, FORCE_MINOR_ID, RESOLVE_MAJOR_ID};
+
+    use super::*;
+
+    #[test]
+    fn audit_flags_wasted_stats() {
+        let mut character = Character::new(0);
+        for buff_id in [FORCE_MAJOR_ID, FORCE_MINOR_ID, FELINE_AMBUSH_ID, HEMORRHAGE_PASSIVE_ID, PIERCING_SPEAR_ID, SUL_XAN_SOULBOUND] {
+            character.add_buff(buff_id, 1);
+        }
+        character.add_buff(ADVANCED_SPECIES_ID, 3);
+        character.add_buff(RESOLVE_MAJOR_ID, 1);
+        character.add_buff(139698, 1); // sislea's defense
+        character.recompute_all_supplemental_state();
+
+        let flags = audit(&character, &Target::with_armour(1, 0));
+        assert!(flags.contains(&AuditFlag::CriticalDamageOvercap { wasted: 14 }));
+        assert!(flags.contains(&AuditFlag::PenetrationOvercap { wasted: character.get_penetration() }));
+        assert!(flags.contains(&AuditFlag::ResistanceOvercap { damage_type: DamageType::MAGIC, wasted: 5948 + 15000 - 16500 }));
+        assert!(!flags.iter().any(|flag| matches!(flag, AuditFlag::ResistanceOvercap { damage_type: DamageType::PHYSICAL, .. })));
+        assert!(!flags.iter().any(|flag| matches!(flag, AuditFlag::DuplicateBuff { .. })));
+    }
+
+    #[test]
+    fn audit_flags_duplicate_buffs() {
+        let mut character = Character::new(0);
+        character.add_buff_from_source(FORCE_MAJOR_ID, 1, 1);
+        character.add_buff_from_source(FORCE_MAJOR_ID, 2, 1);
+        character.recompute_all_supplemental_state();
+
+        let flags = audit(&character, &Target::new(3));
+        assert!(flags.contains(&AuditFlag::DuplicateBuff { id: FORCE_MAJOR_ID, name: "Major Force", sources: vec![1, 2], wasted: 1 }));
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use crate::data::damage_modifier::{BLOCK_COST_BY_ID, BLOCK_MITIGATION_BY_ID};
+use crate::data::sets::{SetBonusType, get_total_bonus};
+use crate::engine::{ID, STACKS};
+use crate::engine::player::breakdown::get_buff_value;
+use crate::engine::player::cost::Cost;
+use crate::engine::player::mitigation::BLOCK_MITIGATION_DEFAULT;
+use crate::models::player::Player;
+
+/// Stamina spent per blocked hit, before any cost reduction.
+pub const BLOCK_COST_DEFAULT: u32 = 1730;
+
+/// Whether the player is blocking, and how much blocking negates and costs.
+#[derive(Clone)]
+pub struct Block {
+    sources: HashMap<ID, STACKS>,
+    blocking: bool,
+    mitigation: f32,
+    cost_multiplier: f32,
+    /// Block mitigation and cost percentages from set bonuses.
+    set_bonuses: (u32, u32),
+    pub is_dirty: bool,
+}
+
+impl Block {
+    pub fn new() -> Self {
+        Self {
+            sources: HashMap::new(),
+            blocking: false,
+            mitigation: 0.0,
+            cost_multiplier: 0.0,
+            set_bonuses: (0, 0),
+            is_dirty: false,
+        }
+    }
+
+    pub fn add_source(&mut self, id: ID, stacks: Option<STACKS>) {
+        self.sources.insert(id, stacks.unwrap_or(1));
+        self.is_dirty = true;
+    }
+
+    pub fn add_source_checked(&mut self, id: ID, stacks: Option<STACKS>) {
+        if Self::is_valid_source(&id) {
+            self.add_source(id, stacks);
+        }
+    }
+
+    pub fn remove_source(&mut self, id: &ID) {
+        self.is_dirty = self.sources.remove(id).is_some();
+    }
+
+    pub fn is_valid_source(id: &ID) -> bool {
+        BLOCK_MITIGATION_BY_ID.get(id).is_some() | BLOCK_COST_BY_ID.get(id).is_some()
+    }
+
+    pub fn refresh(&mut self) {
+        self.mitigation = self.set_bonuses.0 as f32 / 100.0;
+        self.cost_multiplier = self.set_bonuses.1 as f32 / 100.0;
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = BLOCK_MITIGATION_BY_ID.get(id) {
+                self.mitigation += get_buff_value(buff, *stacks) as f32 / 100.0;
+            }
+            if let Some(buff) = BLOCK_COST_BY_ID.get(id) {
+                self.cost_multiplier += get_buff_value(buff, *stacks) as f32 / 100.0;
+            }
+        }
+        self.is_dirty = false;
+    }
+
+    pub fn is_blocking(&self) -> bool {
+        self.blocking
+    }
+
+    pub fn set_blocking(&mut self, blocking: bool) {
+        self.blocking = blocking;
+    }
+
+    /// Fraction of a blocked hit's damage negated.
+    pub fn get_mitigation(&self) -> f32 {
+        (BLOCK_MITIGATION_DEFAULT + self.mitigation).min(1.0)
+    }
+
+    /// Stamina spent per blocked hit. Percentage reductions from buffs and Sturdy are summed, then the flat reduction from glyphs is taken off.
+    pub fn get_cost(&self, cost: &Cost) -> u32 {
+        let multiplier = (1.0 - self.cost_multiplier - cost.get_block_cost_multiplier()).max(0.0);
+        ((BLOCK_COST_DEFAULT as f32 * multiplier) as u32).saturating_sub(cost.get_block_cost_reduction())
+    }
+
+    pub fn update_from_player(&mut self, player: &Player) {
+        self.sources.clear();
+        for (id, stacks) in player.get_buffs() {
+            if Self::is_valid_source(id) {
+                self.add_source(*id, Some(*stacks));
+            }
+        }
+        self.set_bonuses = (0, 0);
+        for set in player.get_active_sets_counts() {
+            self.set_bonuses.0 += get_total_bonus(&set, &SetBonusType::BlockMitigation(None));
+            self.set_bonuses.1 += get_total_bonus(&set, &SetBonusType::BlockCost(None));
+        }
+        self.refresh();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::damage_modifier::{BOUND_AEGIS, DEFENSIVE_STANCE};
+    use crate::data::item_type::{GearSlot, ItemQuality};
+    use crate::engine::player::character::Character;
+    use crate::engine::player::mitigation::IncomingHit;
+    use crate::models::damage::DamageType;
+    use crate::models::player::GearPiece;
+
+    use super::*;
+
+    #[test]
+    fn block_sources_increase_mitigation_and_reduce_cost() {
+        let mut character = Character::new(0);
+        character.recompute_all_supplemental_state();
+        assert_eq!(character.get_block_mitigation(), BLOCK_MITIGATION_DEFAULT);
+        assert_eq!(character.get_block_cost(), BLOCK_COST_DEFAULT);
+
+        character.add_buff(BOUND_AEGIS.id, 1);
+        character.add_buff(DEFENSIVE_STANCE.id, 1);
+        character.recompute_buff_supplemental_state();
+        assert_eq!(character.get_block_mitigation(), 0.55);
+        assert_eq!(character.get_block_cost(), (BLOCK_COST_DEFAULT as f32 * 0.8) as u32);
+    }
+
+    #[test]
+    fn full_belly_barricade_five_piece_bonus_applies_to_blocking() {
+        let mut character = Character::new(0);
+        for slot in [GearSlot::Head, GearSlot::Shoulders, GearSlot::Chest, GearSlot::Hands, GearSlot::Waist] {
+            character.set_gear_piece(&slot, GearPiece {
+                item_id: 65539,
+                effective_level: 66,
+                gear_trait: None,
+                quality: ItemQuality::Legendary,
+                set_id: Some(807),
+                enchant: None,
+            });
+        }
+        character.recompute_all_supplemental_state();
+        assert_eq!(character.get_block_mitigation(), 0.6);
+        assert_eq!(character.get_block_cost(), (BLOCK_COST_DEFAULT as f32 * 0.9) as u32);
+    }
+
+    #[test]
+    fn blocked_hits_drain_stamina_until_the_block_drops() {
+        let mut character = Character::new(0);
+        character.recompute_all_supplemental_state();
+        character.set_blocking(true);
+        let stamina = character.get_stamina();
+        let cost = character.get_block_cost();
+        let hit = IncomingHit::new(1000.0, DamageType::OBLIVION);
+
+        let blocked = character.take_hit(&hit);
+        assert_eq!(blocked.taken, 500.0);
+        assert_eq!(character.get_stamina(), stamina - cost);
+        assert_eq!(character.get_health(), character.get_max_health() - 500);
+
+        while character.get_stamina() >= cost {
+            character.take_hit(&hit);
+        }
+        assert!(character.is_blocking());
+        let unblocked = character.take_hit(&hit);
+        assert_eq!(unblocked.taken, 1000.0);
+        assert!(!character.is_blocking());
+    }
+}
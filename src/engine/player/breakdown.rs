@@ -0,0 +1,105 @@
+use std::fmt;
+
+use crate::data::StatBuff;
+use crate::data::names::get_name;
+use crate::engine::{ID, STACKS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Additive,
+    /// A fraction of the additive total, e.g. 0.2 for 20%.
+    Multiplicative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StatSource {
+    /// The value every character has, from level and attributes.
+    Base,
+    Buff(ID),
+    /// Weapon damage, armour, traits, glyphs and set bonuses, which the components sum before applying.
+    Gear,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contribution {
+    pub source: StatSource,
+    pub name: &'static str,
+    pub stacks: STACKS,
+    pub value: f64,
+    pub operation: Operation,
+}
+
+/// Every contribution to a computed stat, in the order they are summed.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StatBreakdown {
+    pub contributions: Vec<Contribution>,
+    pub total: f64,
+    /// The value before capping, if a cap was applied.
+    pub capped_from: Option<f64>,
+}
+
+impl StatBreakdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_base(&mut self, value: f64) {
+        self.contributions.push(Contribution { source: StatSource::Base, name: "Base", stacks: 1, value, operation: Operation::Additive });
+    }
+
+    pub fn add_gear(&mut self, value: f64) {
+        if value != 0.0 {
+            self.contributions.push(Contribution { source: StatSource::Gear, name: "Gear", stacks: 1, value, operation: Operation::Additive });
+        }
+    }
+
+    pub fn add_buff(&mut self, id: ID, stacks: STACKS, value: f64, operation: Operation) {
+        self.contributions.push(Contribution {
+            source: StatSource::Buff(id),
+            name: get_name(&id).unwrap_or("Unknown"),
+            stacks,
+            value,
+            operation,
+        });
+    }
+
+    /// Sets the final value. `uncapped` is only kept if it differs from `total`.
+    pub fn finish(mut self, total: f64, uncapped: f64) -> Self {
+        self.contributions.sort_by_key(|contribution| contribution.source);
+        self.total = total;
+        self.capped_from = if uncapped != total {Some(uncapped)} else {None};
+        self
+    }
+
+    pub fn get(&self, source: &StatSource) -> Option<&Contribution> {
+        self.contributions.iter().find(|contribution| &contribution.source == source)
+    }
+}
+
+impl fmt::Display for StatBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for contribution in &self.contributions {
+            let id = match contribution.source {
+                StatSource::Buff(id) => format!(" ({id})"),
+                _ => String::new(),
+            };
+            match contribution.operation {
+                Operation::Additive => writeln!(f, "{}{} x{}: +{}", contribution.name, id, contribution.stacks, contribution.value)?,
+                Operation::Multiplicative => writeln!(f, "{}{} x{}: +{}%", contribution.name, id, contribution.stacks, contribution.value * 100.0)?,
+            }
+        }
+        match self.capped_from {
+            Some(uncapped) => write!(f, "Total: {} (capped from {})", self.total, uncapped),
+            None => write!(f, "Total: {}", self.total),
+        }
+    }
+}
+
+pub fn get_buff_value(buff: &StatBuff, stacks: STACKS) -> f64 {
+    buff.value + buff.value_per_stack * stacks as f64
+}
+
+/// Buff value as a whole stat rating, truncated the same way when the stat is updated and when it is broken down.
+pub fn get_buff_rating(buff: &StatBuff, stacks: STACKS) -> u32 {
+    get_buff_value(buff, stacks) as u32
+}
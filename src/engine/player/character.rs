@@ -1,13 +1,39 @@
-use crate::data::item_type::GearSlot;
-use crate::engine::world::event::Event;
+use std::collections::HashMap;
+
+use crate::data::attack::{Attack, get_heavy_attack, get_light_attack};
+use crate::data::item_type::{GearSlot, ItemType};
+use crate::data::poison::{Poison, POISON_STACK_MAXIMUM};
+use crate::data::resource::FoodBuff;
+use crate::data::sets::has_bonus;
+use crate::data::ultimate::ULTIMATE_COST_BY_ID;
+use crate::engine::world::event::{Event, UnitId};
+use crate::engine::world::target::Target;
 use crate::engine::{ID, STACKS};
 use crate::engine::player::armour::{Armour, Penetration};
-use crate::engine::player::critical::{CriticalDamage, CriticalDamageTaken, CriticalChance};
+use crate::engine::player::audit::{AuditFlag, audit};
+use crate::engine::player::block::Block;
+use crate::engine::player::breakdown::StatBreakdown;
+use crate::engine::player::diff::StatDiff;
+use crate::engine::player::healing::Healing;
+use crate::engine::player::mitigation::{IncomingHit, MitigatedHit, get_effective_health, mitigate_incoming};
+use crate::engine::player::sensitivity::{Rotation, StatSensitivity};
+use crate::engine::player::cost::Cost;
+use crate::engine::player::enchant::{WeaponEnchantProc, WeaponEnchants};
+use crate::engine::player::critical::{CriticalDamage, CriticalDamageTaken, CriticalChance, CriticalResistance};
+use crate::engine::player::poison::Poisons;
+use crate::engine::player::potion::{PotionUse, Potions};
 use crate::engine::player::power::Power;
 use crate::engine::player::resource::Resources as ResourceModel;
+use crate::engine::player::sets::{OAKENSOUL_RING_ID, SET_REGISTRY_MAP};
+use crate::engine::player::shield::{Shield, Shields};
+use crate::engine::player::ultimate::Ultimate;
+use crate::engine::player::utility::Utility;
+use crate::engine::warning::Warning;
 use crate::models::damage::DamageType;
-use crate::models::player::{ActiveBar, GearPiece, Player as PlayerModel};
+use crate::models::resource::PlayerAttributeType;
+use crate::models::player::{ActiveBar, ActiveSet, GearPiece, Player as PlayerModel};
 
+#[derive(Clone)]
 pub struct Character {
     player: PlayerModel,
     critical_damage_done: CriticalDamage,
@@ -17,6 +43,17 @@ pub struct Character {
     resources: ResourceModel,
     critical_chance: CriticalChance,
     penetration: Penetration,
+    critical_resistance: CriticalResistance,
+    cost: Cost,
+    utility: Utility,
+    weapon_enchants: WeaponEnchants,
+    poisons: Poisons,
+    potions: Potions,
+    block: Block,
+    shields: Shields,
+    healing: Healing,
+    ultimate: Ultimate,
+    warnings: Vec<Warning>,
 }
 
 impl Character {
@@ -30,6 +67,17 @@ impl Character {
             resources: ResourceModel::new(),
             critical_chance: CriticalChance::new(),
             penetration: Penetration::new(),
+            critical_resistance: CriticalResistance::new(),
+            cost: Cost::new(),
+            utility: Utility::new(),
+            weapon_enchants: WeaponEnchants::new(),
+            poisons: Poisons::new(),
+            potions: Potions::new(),
+            block: Block::new(),
+            shields: Shields::new(),
+            healing: Healing::new(),
+            ultimate: Ultimate::new(),
+            warnings: Vec::new(),
         }
     }
 
@@ -41,23 +89,25 @@ impl Character {
                 }
             }
             Event::BarSwapped {player} => {
-                if player == self.player.id() {
-                    self.swap_bars(None);
+                let swapped = player == self.player.id() && self.swap_bars(None);
+                if swapped {
                     self.recompute_all_supplemental_state();
                 }
             }
-            Event::BuffFaded {target, buff_id,} => {
+            Event::BuffFaded {source, target, buff_id} => {
                 if target == self.player.id() {
-                    self.remove_buff(buff_id);
+                    self.remove_buff_from_source(buff_id, source.unwrap_or(target));
                     self.recompute_buff_supplemental_state();
                 }
             } 
             Event::BuffGained {source, target, buff_id, stacks} => {
                 if target == self.player.id() {
-                    self.add_buff(buff_id, stacks);
+                    self.add_buff_from_source(buff_id, source.unwrap_or(target), stacks);
                     self.recompute_buff_supplemental_state();
                 }
             }
+            Event::BlockStarted {player} if player == self.player.id() => self.set_blocking(true),
+            Event::BlockStopped {player} if player == self.player.id() => self.set_blocking(false),
             // Event::ExternalResourceSource { health, magicka, stamina } => {
             //     self.handle_external_resource_source(health, magicka, stamina);
             // }
@@ -66,16 +116,26 @@ impl Character {
     }
 
     pub fn add_buff(&mut self, id: ID, stacks: STACKS) {
-        self.player.add_buff(id, stacks);
-        self.armour.add_source_checked(id, Some(stacks));
-        self.critical_damage_done.add_source_checked(id, Some(stacks));
-        self.critical_damage_taken.add_source_checked(id, Some(stacks));
-        self.power.add_source_checked(id, Some(stacks));
-        self.resources.add_source_checked(id, Some(stacks));
-        self.critical_chance.add_source_checked(id, Some(stacks));
-        self.penetration.add_source_checked(id, Some(stacks));
+        self.add_buff_from_source(id, self.player.id(), stacks);
     }
 
+    /// Adds a buff applied by `source`. Major and minor effects don't stack, so the components only ever see one instance of each buff.
+    pub fn add_buff_from_source(&mut self, id: ID, source: UnitId, stacks: STACKS) {
+        if FoodBuff::is_valid_source(&id) && let Some(replaced) = self.player.get_food() && replaced != id {
+            self.warnings.push(Warning::MultipleFoodBuffs { replaced, added: id });
+            self.remove_buff(replaced);
+        }
+        self.player.add_buff_from_source(id, source, stacks);
+        self.update_buff_sources(id);
+        self.recompute_buff_supplemental_state();
+    }
+
+    /// Sets the value of a buff which scales with the gear that applied it, until the buff is removed.
+    pub fn set_buff_value(&mut self, id: ID, value: u32) {
+        self.power.set_buff_value(id, value);
+    }
+
+    /// Removes the buff from every source.
     pub fn remove_buff(&mut self, id: ID) {
         self.player.remove_buff(&id);
         self.armour.remove_source(&id);
@@ -85,16 +145,110 @@ impl Character {
         self.resources.remove_source(&id);
         self.critical_chance.remove_source(&id);
         self.penetration.remove_source(&id);
+        self.block.remove_source(&id);
+        self.shields.remove_source(&id);
+        self.healing.remove_source(&id);
+        self.ultimate.remove_source(&id);
+        self.recompute_buff_supplemental_state();
+    }
+
+    /// Removes the buff applied by `source`, keeping it active while any other source remains.
+    pub fn remove_buff_from_source(&mut self, id: ID, source: UnitId) {
+        if !self.player.has_buff(&id) {
+            return;
+        }
+        if self.player.remove_buff_from_source(&id, &source) {
+            self.update_buff_sources(id);
+            self.recompute_buff_supplemental_state();
+        } else {
+            self.remove_buff(id);
+        }
+    }
+
+    /// Passes the buff's current stacks, the highest of any source, on to the components.
+    fn update_buff_sources(&mut self, id: ID) {
+        let stacks = self.player.get_buffs()[&id];
+        self.armour.add_source_checked(id, Some(stacks));
+        self.critical_damage_done.add_source_checked(id, Some(stacks));
+        self.critical_damage_taken.add_source_checked(id, Some(stacks));
+        self.power.add_source_checked(id, Some(stacks));
+        self.resources.add_source_checked(id, Some(stacks));
+        self.critical_chance.add_source_checked(id, Some(stacks));
+        self.penetration.add_source_checked(id, Some(stacks));
+        self.block.add_source_checked(id, Some(stacks));
+        self.shields.add_source_checked(id, Some(stacks));
+        self.healing.add_source_checked(id, Some(stacks));
+        self.ultimate.add_source_checked(id, Some(stacks));
+    }
+
+    pub fn id(&self) -> u32 {
+        self.player.id()
+    }
+
+    /// Eats a food, replacing any existing one without a warning. Returns false if `food_id` is not a known food.
+    pub fn set_food(&mut self, food_id: ID) -> bool {
+        if !FoodBuff::is_valid_source(&food_id) {
+            return false;
+        }
+        if let Some(previous) = self.get_food() {
+            self.remove_buff(previous);
+        }
+        self.add_buff(food_id, 1);
+        true
+    }
+
+    pub fn get_food(&self) -> Option<ID> {
+        self.player.get_food()
+    }
+
+    pub fn get_warnings(&self) -> &Vec<Warning> {
+        &self.warnings
+    }
+
+    pub fn add_warning(&mut self, warning: Warning) {
+        self.warnings.push(warning);
     }
 
     pub fn has_buff(&self, buff_id: u32) -> bool {
         self.player.has_buff(&buff_id)
     }
 
+    pub fn get_buffs(&self) -> &HashMap<ID, STACKS> {
+        self.player.get_buffs()
+    }
+
+    pub fn get_buff_sources(&self, buff_id: &ID) -> Vec<UnitId> {
+        self.player.get_buff_sources(buff_id)
+    }
+
     pub fn get_set_piece_count(&self, set_id: &u16) -> u8 {
         self.player.get_number_of_equipped_set(set_id)
     }
 
+    pub fn get_set_piece_count_on_bar(&self, set_id: &u16, bar: &ActiveBar) -> u8 {
+        self.player.get_number_of_set_on_bar(set_id, bar)
+    }
+
+    /// Whether `set_id` has enough pieces with `bar` active for a set implementation or any of its bonuses. Body and jewelry pieces count on both bars, weapons only on their own.
+    pub fn is_set_active_on_bar(&self, set_id: &u16, bar: &ActiveBar) -> bool {
+        let count = self.get_set_piece_count_on_bar(set_id, bar);
+        match SET_REGISTRY_MAP.get(set_id) {
+            Some(reference) => count >= reference.min_pieces,
+            None => has_bonus(&ActiveSet { set_id: *set_id as u32, count }),
+        }
+    }
+
+    /// Sets active with `bar` active, sorted by id.
+    pub fn get_active_sets_on_bar(&self, bar: &ActiveBar) -> Vec<u16> {
+        let mut sets: Vec<u16> = self.player.get_sets_counts_on_bar(bar)
+            .into_iter()
+            .map(|set| set.set_id as u16)
+            .filter(|set_id| self.is_set_active_on_bar(set_id, bar))
+            .collect();
+        sets.sort();
+        sets
+    }
+
     pub fn get_critical_damage_done(&self) -> u16 {
         self.critical_damage_done.calculate()
     }
@@ -107,10 +261,15 @@ impl Character {
         self.critical_damage_done.calculate_uncapped()
     }
 
-    pub fn get_power(&mut self) -> u32 {
+    pub fn get_power(&self) -> u32 {
         self.power.calculate()
     }
 
+    /// Power against an enemy at `target_health` of their max health.
+    pub fn get_power_against(&self, target_health: f32) -> u32 {
+        self.power.calculate_against(target_health)
+    }
+
     pub fn get_armour(&self, damage_type: &DamageType) -> u32 {
         self.armour.calculate(damage_type)
     }
@@ -127,6 +286,53 @@ impl Character {
         self.resources.get_max_stamina()
     }
 
+    pub fn get_max_resource(&self, resource: &PlayerAttributeType) -> u32 {
+        match resource {
+            PlayerAttributeType::Health => self.get_max_health(),
+            PlayerAttributeType::Magicka => self.get_max_magicka(),
+            PlayerAttributeType::Stamina => self.get_max_stamina(),
+        }
+    }
+
+    pub fn get_health(&self) -> u32 {
+        self.resources.get_current(&PlayerAttributeType::Health)
+    }
+
+    pub fn get_magicka(&self) -> u32 {
+        self.resources.get_current(&PlayerAttributeType::Magicka)
+    }
+
+    pub fn get_stamina(&self) -> u32 {
+        self.resources.get_current(&PlayerAttributeType::Stamina)
+    }
+
+    /// Spends `amount` of `resource` if there is enough of it.
+    pub fn spend_resource(&mut self, resource: &PlayerAttributeType, amount: u32) -> bool {
+        self.resources.spend(resource, amount)
+    }
+
+    /// Restores `amount` of `resource`, up to its maximum.
+    pub fn restore_resource(&mut self, resource: &PlayerAttributeType, amount: u32) {
+        self.resources.restore(resource, amount);
+    }
+
+    /// Refills health, magicka and stamina to their maximums.
+    pub fn fill_resources(&mut self) {
+        self.resources.fill();
+    }
+
+    pub fn get_health_recovery(&self) -> u32 {
+        self.resources.get_health_recovery()
+    }
+
+    pub fn get_magicka_recovery(&self) -> u32 {
+        self.resources.get_magicka_recovery()
+    }
+
+    pub fn get_stamina_recovery(&self) -> u32 {
+        self.resources.get_stamina_recovery()
+    }
+
     pub fn get_critical_chance(&self) -> f32 {
         self.critical_chance.calculate()
     }
@@ -139,22 +345,278 @@ impl Character {
         self.penetration.calculate()
     }
 
-    pub fn swap_bars(&mut self, choice: Option<&ActiveBar>) {
+    pub fn get_critical_resistance(&self) -> f32 {
+        self.critical_resistance.calculate()
+    }
+
+    pub fn get_critical_resistance_raw(&self) -> u32 {
+        self.critical_resistance.get_raw()
+    }
+
+    pub fn get_critical_damage_done_breakdown(&self) -> StatBreakdown {
+        self.critical_damage_done.breakdown()
+    }
+
+    pub fn get_critical_damage_taken_breakdown(&self) -> StatBreakdown {
+        self.critical_damage_taken.breakdown()
+    }
+
+    pub fn get_power_breakdown(&self) -> StatBreakdown {
+        self.power.breakdown()
+    }
+
+    pub fn get_armour_breakdown(&self, damage_type: &DamageType) -> StatBreakdown {
+        self.armour.breakdown(damage_type)
+    }
+
+    pub fn get_max_resource_breakdown(&self, resource: &PlayerAttributeType) -> StatBreakdown {
+        self.resources.breakdown_max(resource)
+    }
+
+    pub fn get_critical_chance_breakdown(&self) -> StatBreakdown {
+        self.critical_chance.breakdown()
+    }
+
+    pub fn get_penetration_breakdown(&self) -> StatBreakdown {
+        self.penetration.breakdown()
+    }
+
+    /// Compares every computed stat on both bars against `other`. Set instances are not re-evaluated per bar.
+    pub fn diff(&self, other: &Character) -> StatDiff {
+        StatDiff::new(self, other)
+    }
+
+    /// Flags wasted stats and duplicated buffs when attacking `target`.
+    pub fn audit(&self, target: &Target) -> Vec<AuditFlag> {
+        audit(self, target)
+    }
+
+    /// Damage `hit` would deal after every mitigation step, without taking it.
+    pub fn mitigate_hit(&self, hit: &IncomingHit) -> MitigatedHit {
+        mitigate_incoming(self, hit)
+    }
+
+    /// Takes `hit`, consuming damage shields before health. While blocking, the hit is blocked if the block cost can be paid, otherwise the block drops.
+    pub fn take_hit(&mut self, hit: &IncomingHit) -> MitigatedHit {
+        let mut hit = hit.clone();
+        if self.block.is_blocking() {
+            hit.blocked = self.resources.spend(&PlayerAttributeType::Stamina, self.get_block_cost());
+            self.block.set_blocking(hit.blocked);
+        }
+        let mitigated = mitigate_incoming(self, &hit);
+        self.shields.absorb(mitigated.absorbed);
+        self.resources.drain(&PlayerAttributeType::Health, mitigated.taken as u32);
+        mitigated
+    }
+
+    /// Adds a damage shield, applying shield strength modifiers and the max health cap. Reapplying `id` replaces the shield. Returns its strength.
+    pub fn add_shield(&mut self, id: ID, amount: f32, expires_at_ms: Option<u64>) -> f32 {
+        self.shields.add(id, amount, self.get_max_health(), expires_at_ms)
+    }
+
+    pub fn remove_shield(&mut self, id: &ID) {
+        self.shields.remove(id);
+    }
+
+    pub fn expire_shields(&mut self, time_ms: u64) {
+        self.shields.expire(time_ms);
+    }
+
+    pub fn get_shields(&self) -> &Vec<Shield> {
+        self.shields.get_shields()
+    }
+
+    /// Total absorb of every active damage shield.
+    pub fn get_shield(&self) -> f32 {
+        self.shields.get_total()
+    }
+
+    /// Total damage absorbed by damage shields so far.
+    pub fn get_damage_absorbed(&self) -> f32 {
+        self.shields.get_absorbed()
+    }
+
+    pub fn is_blocking(&self) -> bool {
+        self.block.is_blocking()
+    }
+
+    pub fn set_blocking(&mut self, blocking: bool) {
+        self.block.set_blocking(blocking);
+    }
+
+    /// Healing done from gear and buffs as a fraction.
+    pub fn get_healing_done(&self) -> f32 {
+        self.utility.get_healing_done() + self.healing.get_healing_done()
+    }
+
+    /// Healing taken from gear and buffs as a fraction.
+    pub fn get_healing_taken(&self) -> f32 {
+        self.healing.get_healing_taken()
+    }
+
+    pub fn get_ultimate(&self) -> u32 {
+        self.ultimate.get_current()
+    }
+
+    /// Gains `amount` ultimate, with a chance of an additional point from Decisive weapons. Returns the ultimate gained.
+    pub fn gain_ultimate(&mut self, amount: u32) -> u32 {
+        self.ultimate.gain(amount, self.utility.get_ultimate_generation_chance())
+    }
+
+    /// Ultimate gained every generation interval from buffs such as Heroism.
+    pub fn get_ultimate_generation(&self) -> u32 {
+        self.ultimate.get_generation()
+    }
+
+    /// Ultimate gained on drinking a potion, from passives such as Catalyst.
+    pub fn get_ultimate_on_potion(&self) -> u32 {
+        self.ultimate.get_on_potion()
+    }
+
+    /// Ultimate cost of `skill_id` after cost reduction, or `None` if it is not an ultimate.
+    pub fn get_ultimate_cost(&self, skill_id: &ID) -> Option<u32> {
+        ULTIMATE_COST_BY_ID.get(skill_id).map(|cost| self.ultimate.get_cost(*cost))
+    }
+
+    /// Spends the ultimate cost of `skill_id`. Returns false, spending nothing, if the player doesn't have enough.
+    pub fn spend_ultimate(&mut self, skill_id: &ID) -> bool {
+        match self.get_ultimate_cost(skill_id) {
+            Some(cost) => self.ultimate.spend(cost),
+            None => true,
+        }
+    }
+
+    /// Fraction of a blocked hit's damage negated.
+    pub fn get_block_mitigation(&self) -> f32 {
+        self.block.get_mitigation()
+    }
+
+    /// Stamina spent per blocked hit.
+    pub fn get_block_cost(&self) -> u32 {
+        self.block.get_cost(&self.cost)
+    }
+
+    pub fn get_effective_health(&self, damage_type: &DamageType, penetration: u32) -> f32 {
+        get_effective_health(self, damage_type, penetration)
+    }
+
+    /// DPS gained per increase of each offensive stat for `rotation` against `target`.
+    pub fn get_sensitivity(&self, target: &Target, rotation: &Rotation) -> StatSensitivity {
+        StatSensitivity::new(self, target, rotation)
+    }
+
+    /// A copy of the character with `bar` active and its stats recomputed. Oakensoul doesn't stop this, as nothing is swapped in the encounter.
+    pub fn on_bar(&self, bar: &ActiveBar) -> Character {
+        let mut character = self.clone();
+        character.player.swap_bars(Some(bar));
+        character.recompute_all_supplemental_state();
+        character
+    }
+
+    pub fn get_cost(&self) -> &Cost {
+        &self.cost
+    }
+
+    pub fn get_utility(&self) -> &Utility {
+        &self.utility
+    }
+
+    /// Procs every weapon glyph on the active bar which is off cooldown.
+    pub fn proc_weapon_enchants(&mut self, time_ms: u64) -> Vec<WeaponEnchantProc> {
+        self.weapon_enchants.proc(&self.player, time_ms)
+    }
+
+    /// Equips a stack of poison from `data::poison` to `GearSlot::Poison` or `GearSlot::BackupPoison`. Returns false if the slot, poison or charges are invalid.
+    pub fn set_poison(&mut self, slot: &GearSlot, poison_id: ID, charges: u16) -> bool {
+        let Some(gear) = Poisons::get_gear_piece(poison_id) else {return false};
+        if !self.poisons.set_charges(slot, charges) {
+            return false;
+        }
+        self.player.set_gear_piece(slot, gear);
+        true
+    }
+
+    pub fn get_poison_charges(&self, slot: &GearSlot) -> Option<u16> {
+        self.poisons.get_charges(slot)
+    }
+
+    /// Consumes a charge of the active bar's poison if it is off cooldown, unequipping it once it runs out.
+    pub fn proc_poison(&mut self, time_ms: u64) -> Option<&'static Poison> {
+        let poison_proc = self.poisons.proc(&self.player, time_ms)?;
+        if poison_proc.used_up {
+            self.player.remove_gear_piece(&poison_proc.slot);
+        }
+        Some(poison_proc.poison)
+    }
+
+    /// Drinks a potion from `data::potion` if the potion cooldown is over.
+    pub fn use_potion(&mut self, potion_id: ID, time_ms: u64) -> Option<PotionUse> {
+        self.potions.use_potion(&self.player, potion_id, time_ms)
+    }
+
+    /// Returns whether the bars were swapped. Nothing changes while Oakensoul is equipped, other than choosing the bar already active.
+    pub fn swap_bars(&mut self, choice: Option<&ActiveBar>) -> bool {
+        if !self.can_swap_bars() && choice != Some(self.get_active_bar()) {
+            self.add_warning(Warning::BarSwapBlocked { set_id: OAKENSOUL_RING_ID });
+            return false;
+        }
         self.player.swap_bars(choice);
+        true
+    }
+
+    pub fn can_swap_bars(&self) -> bool {
+        self.get_set_piece_count(&OAKENSOUL_RING_ID) == 0
+    }
+
+    pub fn get_gear_piece(&self, slot: &GearSlot) -> Option<&GearPiece> {
+        self.player.get_gear_piece(slot)
     }
 
+    /// Poisons are equipped through `set_poison` as a full stack.
     pub fn set_gear_piece(&mut self, slot: &GearSlot, gear: GearPiece) {
+        if Poisons::is_valid_slot(slot) {
+            self.set_poison(slot, gear.item_id, POISON_STACK_MAXIMUM);
+            return;
+        }
         self.player.set_gear_piece(slot, gear);
+        self.recompute_all_supplemental_state();
     }
 
     pub fn set_skills_on_bar(&mut self, bar: &ActiveBar, skills: Vec<u32>) {
         self.player.set_skills(&bar, skills);
+        self.recompute_all_supplemental_state();
     }
 
     pub fn get_bar_of_skill_id(&self, skill: &ID) -> Option<&ActiveBar> {
         self.player.get_bar_of_skill_id(skill)
     }
 
+    /// Item type of the main hand weapon on the active bar.
+    pub fn get_main_hand_type(&self) -> Option<&ItemType> {
+        let slot = match self.player.get_active_bar() {
+            ActiveBar::Primary => GearSlot::MainHand,
+            ActiveBar::Backup => GearSlot::MainHandBackup,
+        };
+        self.player.get_gear_piece(&slot).and_then(|gear| gear.get_item_type())
+    }
+
+    /// Item type of the off hand weapon or shield on the active bar.
+    pub fn get_off_hand_type(&self) -> Option<&ItemType> {
+        let slot = match self.player.get_active_bar() {
+            ActiveBar::Primary => GearSlot::OffHand,
+            ActiveBar::Backup => GearSlot::OffHandBackup,
+        };
+        self.player.get_gear_piece(&slot).and_then(|gear| gear.get_item_type())
+    }
+
+    pub fn get_light_attack(&self) -> &'static Attack {
+        get_light_attack(self.get_main_hand_type(), self.get_off_hand_type())
+    }
+
+    pub fn get_heavy_attack(&self) -> &'static Attack {
+        get_heavy_attack(self.get_main_hand_type(), self.get_off_hand_type())
+    }
+
     pub fn get_active_bar(&self) -> &ActiveBar {
         self.player.get_active_bar()
     }
@@ -171,6 +633,13 @@ impl Character {
         self.resources.update_from_player(&self.player);
         self.critical_chance.update_from_player(&self.player);
         self.penetration.update_from_player(&self.player);
+        self.critical_resistance.update_from_player(&self.player);
+        self.cost.update_from_player(&self.player);
+        self.utility.update_from_player(&self.player);
+        self.block.update_from_player(&self.player);
+        self.shields.update_from_player(&self.player);
+        self.healing.update_from_player(&self.player);
+        self.ultimate.update_from_player(&self.player);
     }
 
     pub fn recompute_buff_supplemental_state(&mut self) {
@@ -183,6 +652,10 @@ impl Character {
         if self.resources.is_dirty {self.resources.refresh()};
         if self.critical_chance.is_dirty {self.critical_chance.refresh()};
         if self.penetration.is_dirty {self.penetration.refresh()};
+        if self.block.is_dirty {self.block.refresh()};
+        if self.shields.is_dirty {self.shields.refresh()};
+        if self.healing.is_dirty {self.healing.refresh()};
+        if self.ultimate.is_dirty {self.ultimate.refresh()};
     }
 
     pub fn set_attributes(&mut self, health: u8, magicka: u8, stamina: u8) {
@@ -192,7 +665,7 @@ impl Character {
 
 #[cfg(test)]
 mod character_integration_test {
-    use crate::{data::{critical_damage::*, item_type::{EnchantType, GearTrait, ItemQuality}, major_minor::*, skill::{CAMOUFLAGED_HUNTER_ID, SOUL_SIPHON_ID, UNDAUNTED_METTLE_ID}}, models::player::GearEnchant};
+    use crate::{data::{armour::FORTIFIED, critical_damage::*, item_type::{EnchantType, GearTrait, ItemQuality}, major_minor::*, skill::{CAMOUFLAGED_HUNTER_ID, SOUL_SIPHON_ID, UNDAUNTED_METTLE_ID}}, engine::player::{breakdown::{Operation, StatSource}, diff::Stat}, models::player::GearEnchant};
 
     use super::*;
 
@@ -691,4 +1164,248 @@ mod character_integration_test {
         
         assert!(character.get_max_magicka() == 35009, "Max magicka incorrect (is {})", character.get_max_magicka())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn traits_apply_to_recovery_and_critical_resistance() {
+        let mut character = Character::new(0);
+
+        character.set_gear_piece(
+            &GearSlot::Chest,
+            GearPiece {
+                item_id: 98304,
+                effective_level: 66,
+                gear_trait: Some(GearTrait::ArmorInvigorating),
+                quality: ItemQuality::Legendary,
+                set_id: None,
+                enchant: None,
+            }
+        );
+        character.set_gear_piece(
+            &GearSlot::Legs,
+            GearPiece {
+                item_id: 98304,
+                effective_level: 66,
+                gear_trait: Some(GearTrait::ArmorImpenetrable),
+                quality: ItemQuality::Legendary,
+                set_id: None,
+                enchant: None,
+            }
+        );
+        character.set_gear_piece(
+            &GearSlot::Ring1,
+            GearPiece {
+                item_id: 0,
+                effective_level: 66,
+                gear_trait: Some(GearTrait::JewelryInfused),
+                quality: ItemQuality::Legendary,
+                set_id: None,
+                enchant: Some(GearEnchant {
+                    glyph: EnchantType::MagickaRegen,
+                    effective_level: 66,
+                    quality: ItemQuality::Legendary,
+                }),
+            }
+        );
+        character.recompute_all_supplemental_state();
+        let max_magicka_without_triune = character.get_max_magicka();
+
+        character.set_gear_piece(
+            &GearSlot::Ring2,
+            GearPiece {
+                item_id: 0,
+                effective_level: 66,
+                gear_trait: Some(GearTrait::JewelryTriune),
+                quality: ItemQuality::Legendary,
+                set_id: None,
+                enchant: None,
+            }
+        );
+        character.recompute_all_supplemental_state();
+
+        // (514 + 174 * 1.6) * (1 + 16%)
+        assert!(character.get_magicka_recovery() == 919, "magicka recovery incorrect (is {})", character.get_magicka_recovery());
+        assert!(character.get_health_recovery() == 358, "health recovery incorrect (is {})", character.get_health_recovery());
+        assert!(character.get_critical_resistance_raw() == 127, "critical resistance incorrect (is {})", character.get_critical_resistance_raw());
+        assert!(character.get_max_magicka() >= max_magicka_without_triune + 435, "max magicka incorrect (is {})", character.get_max_magicka());
+    }
+
+    #[test]
+    fn food_applies_max_resources_and_recovery() {
+        let mut character = Character::new(0);
+        character.recompute_all_supplemental_state();
+        let max_health = character.get_max_health();
+        let health_recovery = character.get_health_recovery();
+        let magicka_recovery = character.get_magicka_recovery();
+
+        assert!(!character.set_food(FORCE_MINOR_ID));
+        assert!(character.get_food().is_none());
+
+        assert!(character.set_food(100498)); // clockwork citrus filet
+        character.recompute_buff_supplemental_state();
+        assert_eq!(character.get_max_health(), max_health + 3326);
+        // (12000 + 3080) * (1 + 6%)
+        assert_eq!(character.get_max_magicka(), 15985);
+        assert_eq!(character.get_health_recovery(), health_recovery + 406);
+        assert_eq!(character.get_magicka_recovery(), magicka_recovery + 338);
+        assert!(character.get_warnings().is_empty());
+    }
+
+    #[test]
+    fn second_food_buff_replaces_the_first_with_a_warning() {
+        let mut character = Character::new(0);
+        character.recompute_all_supplemental_state();
+        let max_health = character.get_max_health();
+
+        character.add_buff(61257, 1); // bistat magicka
+        character.add_buff(84720, 1); // ghastly eye bowl
+        character.recompute_buff_supplemental_state();
+
+        assert_eq!(character.get_food(), Some(84720));
+        // (12000 + 4592) * (1 + 6%)
+        assert_eq!(character.get_max_magicka(), 17588);
+        assert_eq!(character.get_max_health(), max_health, "bistat food was not replaced");
+        assert_eq!(character.get_warnings(), &vec![Warning::MultipleFoodBuffs { replaced: 61257, added: 84720 }]);
+    }
+
+    #[test]
+    fn breakdown_lists_every_source() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(
+            &GearSlot::MainHand,
+            GearPiece {
+                item_id: 172034,
+                effective_level: 66,
+                gear_trait: Some(GearTrait::WeaponSharpened),
+                quality: ItemQuality::Legendary,
+                set_id: None,
+                enchant: None,
+            }
+        );
+        character.add_buff(BRUTALITY_MAJOR_ID, 1);
+        character.recompute_all_supplemental_state();
+
+        let power = character.get_power_breakdown();
+        let brutality = power.get(&StatSource::Buff(BRUTALITY_MAJOR_ID)).expect("major brutality missing");
+        assert_eq!(brutality.name, "Major Brutality");
+        assert_eq!(brutality.operation, Operation::Multiplicative);
+        assert!(power.get(&StatSource::Gear).is_some());
+        assert_eq!(power.total, character.get_power() as f64);
+
+        let penetration = character.get_penetration_breakdown();
+        let additive: f64 = penetration.contributions.iter().map(|contribution| contribution.value).sum();
+        assert_eq!(additive, character.get_penetration() as f64);
+
+        let magicka = character.get_max_resource_breakdown(&PlayerAttributeType::Magicka);
+        assert_eq!(magicka.total, character.get_max_magicka() as f64);
+        assert!(magicka.capped_from.is_none());
+
+        let critical_chance = character.get_critical_chance_breakdown();
+        let chance: f64 = critical_chance.contributions.iter().map(|contribution| contribution.value).sum();
+        assert_eq!(critical_chance.get(&StatSource::Base).unwrap().value, 0.1f32 as f64);
+        assert!((chance - critical_chance.total).abs() < 1e-6, "critical chance contributions sum to {chance}");
+    }
+
+    #[test]
+    fn armour_breakdown_attributes_gear_and_assumed_cp() {
+        let heavy = |item_id| GearPiece {
+            item_id,
+            effective_level: 66,
+            gear_trait: None,
+            quality: ItemQuality::Legendary,
+            set_id: None,
+            enchant: None,
+        };
+        let mut character = Character::new(0);
+        for (slot, item_id) in [(GearSlot::Head, 65539), (GearSlot::Shoulders, 65540), (GearSlot::Chest, 65541), (GearSlot::Hands, 65542)] {
+            character.set_gear_piece(&slot, heavy(item_id));
+        }
+        character.recompute_all_supplemental_state();
+
+        let armour = character.get_armour_breakdown(&DamageType::PHYSICAL);
+        let sum: f64 = armour.contributions.iter().map(|contribution| contribution.value).sum();
+        assert_eq!(sum, armour.total);
+        assert_eq!(armour.get(&StatSource::Buff(FORTIFIED.id)).unwrap().value, 1731.0);
+        assert_eq!(armour.get(&StatSource::Gear).unwrap().value, character.player.get_total_armour() as f64);
+    }
+
+    #[test]
+    fn diff_swapping_precise_for_nirnhoned() {
+        let off_hand = |gear_trait| GearPiece {
+            item_id: 172034,
+            effective_level: 66,
+            gear_trait: Some(gear_trait),
+            quality: ItemQuality::Legendary,
+            set_id: None,
+            enchant: None,
+        };
+        let mut precise = Character::new(0);
+        precise.set_gear_piece(&GearSlot::OffHand, off_hand(GearTrait::WeaponPrecise));
+        precise.recompute_all_supplemental_state();
+        let mut nirnhoned = Character::new(0);
+        nirnhoned.set_gear_piece(&GearSlot::OffHand, off_hand(GearTrait::WeaponNirnhoned));
+        nirnhoned.recompute_all_supplemental_state();
+
+        let diff = precise.diff(&nirnhoned);
+        let critical_chance = diff.get(&ActiveBar::Primary, &Stat::CriticalChance).unwrap();
+        assert!(critical_chance.delta() < 0.0);
+        assert!(critical_chance.sources.iter().any(|source| source.source == StatSource::Gear && source.delta() < 0.0));
+        assert!(diff.get(&ActiveBar::Primary, &Stat::Power).unwrap().delta() > 0.0);
+        assert!(diff.get_changes(&ActiveBar::Backup).is_empty());
+    }
+
+    #[test]
+    fn diff_shows_backup_bar_with_oakensoul_equipped() {
+        let character = |gear_trait| {
+            let mut character = Character::new(0);
+            character.set_gear_piece(&GearSlot::Ring1, GearPiece {
+                item_id: 194512,
+                effective_level: 66,
+                gear_trait: None,
+                quality: ItemQuality::Legendary,
+                set_id: Some(OAKENSOUL_RING_ID),
+                enchant: None,
+            });
+            character.set_gear_piece(&GearSlot::OffHandBackup, GearPiece {
+                item_id: 172034,
+                effective_level: 66,
+                gear_trait: Some(gear_trait),
+                quality: ItemQuality::Legendary,
+                set_id: None,
+                enchant: None,
+            });
+            character
+        };
+        let precise = character(GearTrait::WeaponPrecise);
+        let nirnhoned = character(GearTrait::WeaponNirnhoned);
+
+        let diff = precise.diff(&nirnhoned);
+        assert!(diff.get_changes(&ActiveBar::Primary).is_empty());
+        assert!(diff.get(&ActiveBar::Backup, &Stat::CriticalChance).unwrap().delta() < 0.0);
+        assert!(precise.get_warnings().is_empty());
+        assert_eq!(precise.get_active_bar(), &ActiveBar::Primary);
+    }
+
+    #[test]
+    fn two_handed_weapons_count_as_two_set_pieces_on_their_bar() {
+        let piece = |item_id, set_id| GearPiece {
+            item_id,
+            effective_level: 66,
+            gear_trait: None,
+            quality: ItemQuality::Legendary,
+            set_id: Some(set_id),
+            enchant: None,
+        };
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::Necklace, piece(194512, 722));
+        character.set_gear_piece(&GearSlot::Ring1, piece(194512, 694));
+        character.set_gear_piece(&GearSlot::MainHandBackup, piece(172039, 722));
+
+        assert_eq!(character.get_set_piece_count_on_bar(&722, &ActiveBar::Primary), 1);
+        assert_eq!(character.get_set_piece_count_on_bar(&722, &ActiveBar::Backup), 3);
+        assert_eq!(character.get_active_sets_on_bar(&ActiveBar::Primary), vec![694]);
+        assert_eq!(character.get_active_sets_on_bar(&ActiveBar::Backup), vec![694, 722]);
+
+        character.swap_bars(None);
+        assert_eq!(character.get_set_piece_count(&722), 3);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::data::enchant::{get_enchant_jewellery_reduce_block_cost, get_enchant_jewellery_reduce_feat_cost, get_enchant_jewellery_reduce_spell_cost};
+use crate::data::item_type::{EnchantType, GearTrait};
+use crate::data::traits::{get_armor_sturdy_value, get_armor_well_fitted_value};
+use crate::models::player::Player;
+
+#[derive(Clone)]
+pub struct Cost {
+    spell_cost_reduction: u32,
+    feat_cost_reduction: u32,
+    block_cost_reduction: u32,
+    block_cost_multiplier: f32,
+    dodge_cost_multiplier: f32,
+}
+
+impl Cost {
+    pub fn new() -> Self {
+        Self {
+            spell_cost_reduction: 0,
+            feat_cost_reduction: 0,
+            block_cost_reduction: 0,
+            block_cost_multiplier: 0.0,
+            dodge_cost_multiplier: 0.0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.spell_cost_reduction = 0;
+        self.feat_cost_reduction = 0;
+        self.block_cost_reduction = 0;
+        self.block_cost_multiplier = 0.0;
+        self.dodge_cost_multiplier = 0.0;
+    }
+
+    /// Flat magicka cost reduction for abilities.
+    pub fn get_spell_cost_reduction(&self) -> u32 {
+        self.spell_cost_reduction
+    }
+
+    /// Flat stamina cost reduction for abilities.
+    pub fn get_feat_cost_reduction(&self) -> u32 {
+        self.feat_cost_reduction
+    }
+
+    /// Flat block cost reduction, applied after the percentage reduction.
+    pub fn get_block_cost_reduction(&self) -> u32 {
+        self.block_cost_reduction
+    }
+
+    /// Percentage block cost reduction as a fraction, e.g. 0.04 for 4%.
+    pub fn get_block_cost_multiplier(&self) -> f32 {
+        self.block_cost_multiplier
+    }
+
+    /// Percentage roll dodge and sprint cost reduction as a fraction.
+    pub fn get_dodge_cost_multiplier(&self) -> f32 {
+        self.dodge_cost_multiplier
+    }
+
+    pub fn update_from_player(&mut self, player: &Player) {
+        self.reset();
+        for gear_piece in player.get_active_gear() {
+            match gear_piece.gear_trait {
+                Some(GearTrait::ArmorSturdy) => self.block_cost_multiplier += get_armor_sturdy_value(&gear_piece.quality) - 1.0,
+                Some(GearTrait::ArmorWellFitted) => self.dodge_cost_multiplier += get_armor_well_fitted_value(&gear_piece.quality) - 1.0,
+                _ => {},
+            }
+            if let Some(enchant) = &gear_piece.enchant {
+                let multi = gear_piece.get_enchant_multiplier();
+                match enchant.glyph {
+                    EnchantType::ReduceSpellCost => self.spell_cost_reduction += (multi * get_enchant_jewellery_reduce_spell_cost(&enchant.effective_level, &enchant.quality)) as u32,
+                    EnchantType::ReduceFeatCost => self.feat_cost_reduction += (multi * get_enchant_jewellery_reduce_feat_cost(&enchant.effective_level, &enchant.quality)) as u32,
+                    EnchantType::ReduceBlockAndBash => self.block_cost_reduction += (multi * get_enchant_jewellery_reduce_block_cost(&enchant.effective_level, &enchant.quality)) as u32,
+                    _ => {},
+                }
+            }
+        }
+    }
+}
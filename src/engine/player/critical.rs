@@ -4,11 +4,13 @@ use crate::data::critical_chance::{CRITICAL_CHANCE_BOTH, CRITICAL_CHANCE_SPELL,
 use crate::data::critical_damage::{CRITICAL_DAMAGE_DONE_BY_ID, CRITICAL_DAMAGE_TAKEN_BY_ID, DEXTERITY_ID, HEAVY_WEAPONS_ID, THE_SHADOW_ID, TWIN_BLADE_AND_BLUNT_ID};
 use crate::data::item_type::{GearTrait, ItemType, is_two_handed_weapon_option};
 use crate::data::sets::{SetBonusType, get_total_bonus};
-use crate::data::traits::get_weapon_precise_value;
+use crate::data::traits::{get_armor_impenetrable_value, get_weapon_precise_value};
 use crate::engine::{ID, STACKS};
-use crate::models::critical::{CriticalDamage as CriticalDamageModel, CriticalChance as CriticalChanceModel};
+use crate::engine::player::breakdown::{Operation, StatBreakdown, get_buff_value};
+use crate::models::critical::{CRIT_DAMAGE_SCALE, CriticalDamage as CriticalDamageModel, CriticalChance as CriticalChanceModel, CriticalResistance as CriticalResistanceModel};
 use crate::models::player::Player;
 
+#[derive(Clone)]
 pub struct CriticalDamage {
     pub sources: HashMap<ID, STACKS>,
     critical_damage: CriticalDamageModel,
@@ -66,6 +68,17 @@ impl CriticalDamage {
         self.critical_damage.calculate_uncapped()
     }
 
+    pub fn breakdown(&self) -> StatBreakdown {
+        let mut breakdown = StatBreakdown::new();
+        breakdown.add_base(self.critical_damage.get_base() as f64);
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = CRITICAL_DAMAGE_DONE_BY_ID.get(id) {
+                breakdown.add_buff(*id, *stacks, get_buff_value(buff, *stacks) as u16 as f64, Operation::Additive);
+            }
+        }
+        breakdown.finish(self.calculate() as f64, (self.calculate_uncapped() / CRIT_DAMAGE_SCALE) as f64)
+    }
+
     pub fn update_from_player(&mut self, player: &Player) {
         self.critical_damage.reset();
         self.sources.clear();
@@ -92,6 +105,7 @@ impl CriticalDamage {
     }
 }
 
+#[derive(Clone)]
 pub struct CriticalDamageTaken {
     pub sources: HashMap<ID, STACKS>,
     critical_damage_taken: u8,
@@ -144,6 +158,17 @@ impl CriticalDamageTaken {
         self.critical_damage_taken
     }
 
+    pub fn breakdown(&self) -> StatBreakdown {
+        let mut breakdown = StatBreakdown::new();
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = CRITICAL_DAMAGE_TAKEN_BY_ID.get(id) {
+                breakdown.add_buff(*id, *stacks, get_buff_value(buff, *stacks).round() as u8 as f64, Operation::Additive);
+            }
+        }
+        let total = self.calculate() as f64;
+        breakdown.finish(total, total)
+    }
+
     pub fn update_from_player(&mut self, player: &Player) {
         self.critical_damage_taken = 0;
         self.sources.clear();
@@ -157,6 +182,7 @@ impl CriticalDamageTaken {
     }
 }
 
+#[derive(Clone)]
 pub struct CriticalChance {
     pub sources: HashMap<ID, STACKS>,
     weapon_critical: CriticalChanceModel,
@@ -224,6 +250,22 @@ impl CriticalChance {
         self.weapon_critical.get_raw().max(self.spell_critical.get_raw())
     }
 
+    /// Breakdown of whichever of weapon and spell critical is higher, with every rating converted to critical chance as a fraction.
+    pub fn breakdown(&self) -> StatBreakdown {
+        let use_spell = self.spell_critical.get_raw() > self.weapon_critical.get_raw();
+        let (critical, specific) = if use_spell {(&self.spell_critical, &CRITICAL_CHANCE_SPELL)} else {(&self.weapon_critical, &CRITICAL_CHANCE_WEAPON)};
+        let mut breakdown = StatBreakdown::new();
+        breakdown.add_base(critical.get_base() as f64);
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = CRITICAL_CHANCE_BOTH.get(id).or_else(|| specific.get(id)) {
+                let rating = get_buff_value(buff, *stacks) as u32;
+                breakdown.add_buff(*id, *stacks, CriticalChanceModel::get_chance_from_rating(rating) as f64, Operation::Additive);
+            }
+        }
+        breakdown.add_gear(CriticalChanceModel::get_chance_from_rating(self.set_additive) as f64);
+        breakdown.finish(critical.calculate() as f64, critical.calculate_uncapped() as f64)
+    }
+
     pub fn update_from_player(&mut self, player: &Player) {
         self.weapon_critical.reset();
         self.spell_critical.reset();
@@ -251,9 +293,42 @@ impl CriticalChance {
     }
 }
 
+#[derive(Clone)]
+pub struct CriticalResistance {
+    critical_resistance: CriticalResistanceModel,
+}
+
+impl CriticalResistance {
+    pub fn new() -> Self {
+        Self {
+            critical_resistance: CriticalResistanceModel::default(),
+        }
+    }
+
+    pub fn calculate(&self) -> f32 {
+        self.critical_resistance.calculate()
+    }
+
+    pub fn get_raw(&self) -> u32 {
+        self.critical_resistance.get_raw()
+    }
+
+    pub fn update_from_player(&mut self, player: &Player) {
+        self.critical_resistance.reset();
+        for set in player.get_active_sets_counts() {
+            self.critical_resistance.add_to_additive(get_total_bonus(&set, &SetBonusType::CriticalResistance(None)));
+        }
+        for gear_piece in player.get_active_gear() {
+            if let Some(GearTrait::ArmorImpenetrable) = gear_piece.gear_trait {
+                self.critical_resistance.add_to_additive(get_armor_impenetrable_value(&gear_piece.quality) as u32);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{data::{item_type::{GearSlot, ItemQuality}, major_minor::*}, engine::player::character::Character, models::player::GearPiece};
+    use crate::{data::{item_type::{GearSlot, ItemQuality}, major_minor::*}, engine::{player::character::Character, world::event::{GameState, World}}, models::player::GearPiece};
 
     use super::*;
 
@@ -294,6 +369,19 @@ mod tests {
         assert_eq!(crit.calculate(), 62);
     }
 
+    #[test]
+    fn breakdown_reports_cap() {
+        let mut crit = CriticalDamage::new();
+
+        crit.add_source(DEXTERITY_ID, Some(50));
+        crit.add_source(FORCE_MINOR_ID, Some(1));
+        crit.refresh();
+        let breakdown = crit.breakdown();
+        assert_eq!(breakdown.contributions.len(), 3);
+        assert_eq!(breakdown.total, 125.0);
+        assert_eq!(breakdown.capped_from, Some(160.0));
+    }
+
     #[test]
     fn twin_blade_and_blunt_scales_with_axes() {
         let mut character = Character::new(0);
@@ -361,7 +449,11 @@ mod tests {
             },
         );
 
-        let crit = character.get_critical_damage_done();
+        // set bonuses are applied by the world's set manager
+        let mut state = GameState::new();
+        state.add_player(character);
+
+        let crit = state.character(0).get_critical_damage_done();
         assert!(crit == 60);
     }
 }
\ No newline at end of file
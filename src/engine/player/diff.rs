@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+
+use crate::engine::player::breakdown::{StatBreakdown, StatSource};
+use crate::engine::player::character::Character;
+use crate::models::damage::DamageType;
+use crate::models::player::ActiveBar;
+use crate::models::resource::PlayerAttributeType;
+
+const DAMAGE_TYPES: [DamageType; 9] = [
+    DamageType::BLEED,
+    DamageType::COLD,
+    DamageType::DISEASE,
+    DamageType::FIRE,
+    DamageType::MAGIC,
+    DamageType::OBLIVION,
+    DamageType::PHYSICAL,
+    DamageType::POISON,
+    DamageType::SHOCK,
+];
+
+const RESOURCES: [PlayerAttributeType; 3] = [
+    PlayerAttributeType::Health,
+    PlayerAttributeType::Magicka,
+    PlayerAttributeType::Stamina,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stat {
+    Power,
+    CriticalChance,
+    CriticalDamage,
+    CriticalDamageTaken,
+    CriticalResistance,
+    Penetration,
+    Armour(DamageType),
+    MaxResource(PlayerAttributeType),
+    Recovery(PlayerAttributeType),
+    SpellCostReduction,
+    FeatCostReduction,
+    BlockCostReduction,
+    BlockCostMultiplier,
+    DodgeCostMultiplier,
+    BlockMitigation,
+    BlockCost,
+    SynergyDamage,
+    MovementSpeed,
+    UltimateGenerationChance,
+    StatusEffectChance,
+    HealingDone,
+    HealingTaken,
+    Experience,
+}
+
+impl Stat {
+    pub fn all() -> Vec<Stat> {
+        let mut stats = vec![
+            Stat::Power,
+            Stat::CriticalChance,
+            Stat::CriticalDamage,
+            Stat::CriticalDamageTaken,
+            Stat::CriticalResistance,
+            Stat::Penetration,
+        ];
+        stats.extend(DAMAGE_TYPES.iter().map(|damage_type| Stat::Armour(*damage_type)));
+        stats.extend(RESOURCES.iter().map(|resource| Stat::MaxResource(*resource)));
+        stats.extend(RESOURCES.iter().map(|resource| Stat::Recovery(*resource)));
+        stats.extend([
+            Stat::SpellCostReduction,
+            Stat::FeatCostReduction,
+            Stat::BlockCostReduction,
+            Stat::BlockCostMultiplier,
+            Stat::DodgeCostMultiplier,
+            Stat::BlockMitigation,
+            Stat::BlockCost,
+            Stat::SynergyDamage,
+            Stat::MovementSpeed,
+            Stat::UltimateGenerationChance,
+            Stat::StatusEffectChance,
+            Stat::HealingDone,
+            Stat::HealingTaken,
+            Stat::Experience,
+        ]);
+        stats
+    }
+
+    pub fn get_value(&self, character: &Character) -> f64 {
+        match self {
+            Stat::Power => character.get_power() as f64,
+            Stat::CriticalChance => character.get_critical_chance() as f64,
+            Stat::CriticalDamage => character.get_critical_damage_done() as f64,
+            Stat::CriticalDamageTaken => character.get_critical_damage_taken() as f64,
+            Stat::CriticalResistance => character.get_critical_resistance_raw() as f64,
+            Stat::Penetration => character.get_penetration() as f64,
+            Stat::Armour(damage_type) => character.get_armour(damage_type) as f64,
+            Stat::MaxResource(PlayerAttributeType::Health) => character.get_max_health() as f64,
+            Stat::MaxResource(PlayerAttributeType::Magicka) => character.get_max_magicka() as f64,
+            Stat::MaxResource(PlayerAttributeType::Stamina) => character.get_max_stamina() as f64,
+            Stat::Recovery(PlayerAttributeType::Health) => character.get_health_recovery() as f64,
+            Stat::Recovery(PlayerAttributeType::Magicka) => character.get_magicka_recovery() as f64,
+            Stat::Recovery(PlayerAttributeType::Stamina) => character.get_stamina_recovery() as f64,
+            Stat::SpellCostReduction => character.get_cost().get_spell_cost_reduction() as f64,
+            Stat::FeatCostReduction => character.get_cost().get_feat_cost_reduction() as f64,
+            Stat::BlockCostReduction => character.get_cost().get_block_cost_reduction() as f64,
+            Stat::BlockCostMultiplier => character.get_cost().get_block_cost_multiplier() as f64,
+            Stat::DodgeCostMultiplier => character.get_cost().get_dodge_cost_multiplier() as f64,
+            Stat::BlockMitigation => character.get_block_mitigation() as f64,
+            Stat::BlockCost => character.get_block_cost() as f64,
+            Stat::SynergyDamage => character.get_utility().get_synergy_damage() as f64,
+            Stat::MovementSpeed => character.get_utility().get_movement_speed() as f64,
+            Stat::UltimateGenerationChance => character.get_utility().get_ultimate_generation_chance() as f64,
+            Stat::StatusEffectChance => character.get_utility().get_status_effect_chance() as f64,
+            Stat::HealingDone => character.get_healing_done() as f64,
+            Stat::HealingTaken => character.get_healing_taken() as f64,
+            Stat::Experience => character.get_utility().get_experience() as f64,
+        }
+    }
+
+    /// Only the stats from the components which track their sources have a breakdown.
+    pub fn get_breakdown(&self, character: &Character) -> Option<StatBreakdown> {
+        match self {
+            Stat::Power => Some(character.get_power_breakdown()),
+            Stat::CriticalChance => Some(character.get_critical_chance_breakdown()),
+            Stat::CriticalDamage => Some(character.get_critical_damage_done_breakdown()),
+            Stat::CriticalDamageTaken => Some(character.get_critical_damage_taken_breakdown()),
+            Stat::Penetration => Some(character.get_penetration_breakdown()),
+            Stat::Armour(damage_type) => Some(character.get_armour_breakdown(damage_type)),
+            Stat::MaxResource(resource) => Some(character.get_max_resource_breakdown(resource)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceDelta {
+    pub source: StatSource,
+    pub name: &'static str,
+    pub before: f64,
+    pub after: f64,
+}
+
+impl SourceDelta {
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatDelta {
+    pub stat: Stat,
+    pub before: f64,
+    pub after: f64,
+    /// Every source whose contribution differs between the two characters.
+    pub sources: Vec<SourceDelta>,
+}
+
+impl StatDelta {
+    fn new(stat: Stat, before: &Character, after: &Character) -> Self {
+        let mut sources: BTreeMap<StatSource, SourceDelta> = BTreeMap::new();
+        if let (Some(before_breakdown), Some(after_breakdown)) = (stat.get_breakdown(before), stat.get_breakdown(after)) {
+            for contribution in &before_breakdown.contributions {
+                sources.entry(contribution.source).or_insert(SourceDelta { source: contribution.source, name: contribution.name, before: 0.0, after: 0.0 }).before += contribution.value;
+            }
+            for contribution in &after_breakdown.contributions {
+                sources.entry(contribution.source).or_insert(SourceDelta { source: contribution.source, name: contribution.name, before: 0.0, after: 0.0 }).after += contribution.value;
+            }
+        }
+        Self {
+            stat,
+            before: stat.get_value(before),
+            after: stat.get_value(after),
+            sources: sources.into_values().filter(|source| source.delta() != 0.0).collect(),
+        }
+    }
+
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+/// Every computed stat of two characters on both bars, from `self` (before) to `other` (after).
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatDiff {
+    pub primary: Vec<StatDelta>,
+    pub backup: Vec<StatDelta>,
+}
+
+impl StatDiff {
+    pub fn new(before: &Character, after: &Character) -> Self {
+        Self {
+            primary: Self::diff_bar(before, after, &ActiveBar::Primary),
+            backup: Self::diff_bar(before, after, &ActiveBar::Backup),
+        }
+    }
+
+    fn diff_bar(before: &Character, after: &Character, bar: &ActiveBar) -> Vec<StatDelta> {
+        let before = before.on_bar(bar);
+        let after = after.on_bar(bar);
+        Stat::all().into_iter().map(|stat| StatDelta::new(stat, &before, &after)).collect()
+    }
+
+    pub fn get(&self, bar: &ActiveBar, stat: &Stat) -> Option<&StatDelta> {
+        let deltas = match bar {
+            ActiveBar::Primary => &self.primary,
+            ActiveBar::Backup => &self.backup,
+        };
+        deltas.iter().find(|delta| &delta.stat == stat)
+    }
+
+    /// The stats which differ on `bar`.
+    pub fn get_changes(&self, bar: &ActiveBar) -> Vec<&StatDelta> {
+        let deltas = match bar {
+            ActiveBar::Primary => &self.primary,
+            ActiveBar::Backup => &self.backup,
+        };
+        deltas.iter().filter(|delta| delta.delta() != 0.0).collect()
+    }
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use crate::data::enchant::{WEAPON_ENCHANT_COOLDOWN_MS, WeaponEnchant, get_enchant_weapon_value, get_weapon_enchant};
+use crate::data::item_type::GearSlot;
+use crate::models::player::{ActiveBar, Player};
+
+pub struct WeaponEnchantProc {
+    pub slot: GearSlot,
+    pub enchant: &'static WeaponEnchant,
+    pub value: f32,
+}
+
+/// Tracks the cooldown of each weapon glyph. Each weapon slot has its own cooldown, so the back bar glyphs are ready after a swap.
+#[derive(Clone)]
+pub struct WeaponEnchants {
+    ready_at_ms: HashMap<GearSlot, u64>,
+}
+
+impl WeaponEnchants {
+    pub fn new() -> Self {
+        Self {
+            ready_at_ms: HashMap::new(),
+        }
+    }
+
+    pub fn is_ready(&self, slot: &GearSlot, time_ms: u64) -> bool {
+        self.ready_at_ms.get(slot).is_none_or(|ready_at| time_ms >= *ready_at)
+    }
+
+    /// Returns every glyph on the active bar off cooldown and puts them on cooldown.
+    pub fn proc(&mut self, player: &Player, time_ms: u64) -> Vec<WeaponEnchantProc> {
+        let slots = match player.get_active_bar() {
+            ActiveBar::Primary => [GearSlot::MainHand, GearSlot::OffHand],
+            ActiveBar::Backup => [GearSlot::MainHandBackup, GearSlot::OffHandBackup],
+        };
+
+        let mut procs = Vec::new();
+        for slot in slots {
+            let Some(gear) = player.get_gear_piece(&slot) else {continue};
+            let Some(glyph) = &gear.enchant else {continue};
+            let Some(enchant) = get_weapon_enchant(&glyph.glyph) else {continue};
+            if !self.is_ready(&slot, time_ms) {continue}
+
+            self.ready_at_ms.insert(slot, time_ms + WEAPON_ENCHANT_COOLDOWN_MS);
+            procs.push(WeaponEnchantProc {
+                slot,
+                enchant,
+                value: get_enchant_weapon_value(enchant, &glyph.effective_level, &glyph.quality) * gear.get_enchant_multiplier(),
+            });
+        }
+        procs
+    }
+}
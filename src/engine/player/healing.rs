@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use crate::data::healing::{HEALING_DONE_BY_ID, HEALING_TAKEN_BY_ID};
+use crate::data::sets::{SetBonusType, get_total_bonus};
+use crate::engine::{ID, STACKS};
+use crate::engine::player::breakdown::get_buff_value;
+use crate::engine::player::character::Character;
+use crate::models::player::Player;
+use crate::models::resource::PlayerAttributeType;
+
+/// Healing done and healing taken modifiers from buffs, and healing taken from sets. Healing done from gear is in `Utility`.
+#[derive(Clone)]
+pub struct Healing {
+    sources: HashMap<ID, STACKS>,
+    healing_done: f32,
+    healing_taken: f32,
+    gear_healing_taken: f32,
+    pub is_dirty: bool,
+}
+
+impl Healing {
+    pub fn new() -> Self {
+        Self {
+            sources: HashMap::new(),
+            healing_done: 0.0,
+            healing_taken: 0.0,
+            gear_healing_taken: 0.0,
+            is_dirty: false,
+        }
+    }
+
+    pub fn add_source(&mut self, id: ID, stacks: Option<STACKS>) {
+        self.sources.insert(id, stacks.unwrap_or(1));
+        self.is_dirty = true;
+    }
+
+    pub fn add_source_checked(&mut self, id: ID, stacks: Option<STACKS>) {
+        if Self::is_valid_source(&id) {
+            self.add_source(id, stacks);
+        }
+    }
+
+    pub fn remove_source(&mut self, id: &ID) {
+        self.is_dirty = self.sources.remove(id).is_some();
+    }
+
+    pub fn is_valid_source(id: &ID) -> bool {
+        HEALING_DONE_BY_ID.get(id).is_some() | HEALING_TAKEN_BY_ID.get(id).is_some()
+    }
+
+    pub fn refresh(&mut self) {
+        self.healing_done = 0.0;
+        self.healing_taken = self.gear_healing_taken;
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = HEALING_DONE_BY_ID.get(id) {
+                self.healing_done += get_buff_value(buff, *stacks) as f32 / 100.0;
+            }
+            if let Some(buff) = HEALING_TAKEN_BY_ID.get(id) {
+                self.healing_taken += get_buff_value(buff, *stacks) as f32 / 100.0;
+            }
+        }
+        self.is_dirty = false;
+    }
+
+    /// Healing done from buffs as a fraction.
+    pub fn get_healing_done(&self) -> f32 {
+        self.healing_done
+    }
+
+    /// Healing taken as a fraction.
+    pub fn get_healing_taken(&self) -> f32 {
+        self.healing_taken
+    }
+
+    pub fn update_from_player(&mut self, player: &Player) {
+        self.sources.clear();
+        for (id, stacks) in player.get_buffs() {
+            if Self::is_valid_source(id) {
+                self.add_source(*id, Some(*stacks));
+            }
+        }
+        self.gear_healing_taken = 0.0;
+        for set in player.get_active_sets_counts() {
+            self.gear_healing_taken += get_total_bonus(&set, &SetBonusType::HealingTaken(None)) as f32 / 100.0;
+        }
+        self.refresh();
+    }
+}
+
+impl Default for Healing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A heal, with a tooltip of `resource_coefficient * max resource + power_coefficient * power` of the caster.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heal {
+    pub ability_id: ID,
+    pub resource: PlayerAttributeType,
+    pub resource_coefficient: f32,
+    pub power_coefficient: f32,
+    pub critical: bool,
+}
+
+impl Heal {
+    pub fn new(ability_id: ID, resource: PlayerAttributeType, resource_coefficient: f32, power_coefficient: f32) -> Self {
+        Self {
+            ability_id,
+            resource,
+            resource_coefficient,
+            power_coefficient,
+            critical: false,
+        }
+    }
+
+    pub fn with_critical(mut self) -> Self {
+        self.critical = true;
+        self
+    }
+
+    pub fn get_tooltip(&self, caster: &Character) -> f32 {
+        let max_resource = match self.resource {
+            PlayerAttributeType::Health => caster.get_max_health(),
+            PlayerAttributeType::Magicka => caster.get_max_magicka(),
+            PlayerAttributeType::Stamina => caster.get_max_stamina(),
+        };
+        self.resource_coefficient * max_resource as f32 + self.power_coefficient * caster.get_power() as f32
+    }
+}
+
+/// The healing remaining after each step of the pipeline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealResult {
+    pub tooltip: f32,
+    /// Including the critical bonus.
+    pub raw: f32,
+    /// After the caster's healing done and the target's healing taken.
+    pub amount: f32,
+    /// Health actually restored.
+    pub effective: f32,
+    pub overheal: f32,
+}
+
+/// Runs `heal` from `caster` on `target`, without restoring any health.
+pub fn calculate_heal(caster: &Character, target: &Character, heal: &Heal) -> HealResult {
+    let tooltip = heal.get_tooltip(caster);
+    let raw = if heal.critical {tooltip * (1.0 + caster.get_critical_damage_done() as f32 / 100.0)} else {tooltip};
+    let amount = raw * (1.0 + caster.get_healing_done()).max(0.0) * (1.0 + target.get_healing_taken()).max(0.0);
+    let missing = target.get_max_health().saturating_sub(target.get_health()) as f32;
+    let effective = amount.min(missing);
+    HealResult {
+        tooltip,
+        raw,
+        amount,
+        effective,
+        overheal: amount - effective,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::major_minor::{DEFILE_MINOR_ID, MENDING_MAJOR_ID, VITALITY_MAJOR_ID};
+    use crate::engine::player::mitigation::IncomingHit;
+    use crate::models::damage::DamageType;
+
+    use super::*;
+
+    #[test]
+    fn heals_apply_modifiers_crits_and_overheal() {
+        let mut caster = Character::new(0);
+        caster.add_buff(MENDING_MAJOR_ID, 1);
+        caster.recompute_all_supplemental_state();
+        let mut target = Character::new(1);
+        target.add_buff(VITALITY_MAJOR_ID, 1);
+        target.add_buff(DEFILE_MINOR_ID, 1);
+        target.recompute_all_supplemental_state();
+        assert_eq!(caster.get_healing_done(), 0.16);
+        assert!((target.get_healing_taken() - 0.06).abs() < 1e-6);
+
+        let heal = Heal::new(1, PlayerAttributeType::Magicka, 0.1, 1.0);
+        let tooltip = heal.get_tooltip(&caster);
+        let full = calculate_heal(&caster, &target, &heal);
+        assert_eq!(full.effective, 0.0);
+        assert_eq!(full.overheal, full.amount);
+        assert!((full.amount - tooltip * 1.16 * 1.06).abs() < 0.1);
+
+        target.take_hit(&IncomingHit::new(100000.0, DamageType::OBLIVION));
+        let critical = calculate_heal(&caster, &target, &heal.clone().with_critical());
+        assert_eq!(critical.raw, tooltip * (1.0 + caster.get_critical_damage_done() as f32 / 100.0));
+        assert_eq!(critical.effective, critical.amount);
+        assert_eq!(critical.overheal, 0.0);
+    }
+}
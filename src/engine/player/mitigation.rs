@@ -0,0 +1,159 @@
+use crate::data::damage_modifier::DAMAGE_TAKEN_BY_ID;
+use crate::engine::player::breakdown::get_buff_value;
+use crate::engine::player::character::Character;
+use crate::engine::world::damage::mitigate;
+use crate::models::damage::DamageType;
+
+/// Fraction of damage negated while blocking, before any block mitigation sources.
+pub const BLOCK_MITIGATION_DEFAULT: f32 = 0.5;
+
+/// A hit against a player, before any mitigation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncomingHit {
+    pub amount: f32,
+    pub damage_type: DamageType,
+    pub penetration: u32,
+    /// Critical damage of the attacker as a percentage, if the hit is a critical.
+    pub critical_damage: Option<u16>,
+    pub blocked: bool,
+}
+
+impl IncomingHit {
+    pub fn new(amount: f32, damage_type: DamageType) -> Self {
+        Self {
+            amount,
+            damage_type,
+            penetration: 0,
+            critical_damage: None,
+            blocked: false,
+        }
+    }
+
+    pub fn with_penetration(mut self, penetration: u32) -> Self {
+        self.penetration = penetration;
+        self
+    }
+
+    pub fn with_critical(mut self, critical_damage: u16) -> Self {
+        self.critical_damage = Some(critical_damage);
+        self
+    }
+
+    pub fn with_block(mut self) -> Self {
+        self.blocked = true;
+        self
+    }
+}
+
+/// The damage remaining after each step of the pipeline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MitigatedHit {
+    /// Damage including the critical bonus.
+    pub raw: f32,
+    /// After Protection, Vulnerability and other damage taken modifiers.
+    pub modified: f32,
+    /// After armour less the attacker's penetration.
+    pub resisted: f32,
+    /// After block mitigation, the same as `resisted` if the hit wasn't blocked.
+    pub blocked: f32,
+    /// Damage absorbed by damage shields.
+    pub absorbed: f32,
+    /// Damage taken to health.
+    pub taken: f32,
+}
+
+/// Multiplier on damage taken from the player's Protection and Vulnerability buffs.
+pub fn get_damage_taken_multiplier(character: &Character) -> f32 {
+    let mut modifier = 0.0;
+    for (id, stacks) in character.get_buffs() {
+        if let Some(buff) = DAMAGE_TAKEN_BY_ID.get(id) {
+            modifier += get_buff_value(buff, *stacks);
+        }
+    }
+    (1.0 + modifier as f32 / 100.0).max(0.0)
+}
+
+/// Runs `hit` through the player's mitigation, including their damage shields.
+pub fn mitigate_incoming(character: &Character, hit: &IncomingHit) -> MitigatedHit {
+    let raw = match hit.critical_damage {
+        Some(critical_damage) => {
+            let bonus = (critical_damage as f32 + character.get_critical_damage_taken() as f32) / 100.0 - character.get_critical_resistance();
+            hit.amount * (1.0 + bonus.max(0.0))
+        },
+        None => hit.amount,
+    };
+    let modified = raw * get_damage_taken_multiplier(character);
+    let resisted = mitigate(modified, &hit.damage_type, character.get_armour(&hit.damage_type), hit.penetration);
+    let blocked = if hit.blocked {resisted * (1.0 - character.get_block_mitigation())} else {resisted};
+    let absorbed = blocked.min(character.get_shield());
+    MitigatedHit {
+        raw,
+        modified,
+        resisted,
+        blocked,
+        absorbed,
+        taken: blocked - absorbed,
+    }
+}
+
+/// Raw damage of `damage_type` needed to kill the player from full health through their damage shields, ignoring critical hits and blocking.
+pub fn get_effective_health(character: &Character, damage_type: &DamageType, penetration: u32) -> f32 {
+    let taken_per_point = mitigate_incoming(character, &IncomingHit::new(1.0, *damage_type).with_penetration(penetration)).blocked;
+    if taken_per_point <= 0.0 {
+        return f32::INFINITY;
+    }
+    (character.get_max_health() as f32 + character.get_shield()) / taken_per_point
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::major_minor::{BRITTLE_MAJOR_ID, PROTECTION_MAJOR_ID, RESOLVE_MAJOR_ID};
+
+    use super::*;
+
+    fn tank() -> Character {
+        let mut character = Character::new(0);
+        character.add_buff(PROTECTION_MAJOR_ID, 1);
+        character.add_buff(RESOLVE_MAJOR_ID, 1);
+        character.recompute_all_supplemental_state();
+        character
+    }
+
+    #[test]
+    fn physical_hit_is_mitigated_step_by_step() {
+        let mut character = tank();
+        let resisted = 50000.0 * 0.9 * (1.0 - 5948.0 / 33000.0);
+
+        let hit = character.mitigate_hit(&IncomingHit::new(50000.0, DamageType::PHYSICAL));
+        assert_eq!(hit.modified, 45000.0);
+        assert!((hit.taken - resisted).abs() < 1.0, "taken {}", hit.taken);
+
+        character.add_shield(1, 5000.0, None);
+        let hit = character.mitigate_hit(&IncomingHit::new(50000.0, DamageType::PHYSICAL).with_penetration(5948).with_block());
+        assert_eq!(hit.resisted, 45000.0);
+        assert_eq!(hit.blocked, 22500.0);
+        assert_eq!(hit.absorbed, 5000.0);
+        assert_eq!(hit.taken, 17500.0);
+        assert_eq!(character.get_shield(), 5000.0);
+    }
+
+    #[test]
+    fn critical_hits_include_critical_damage_taken() {
+        let mut character = tank();
+        let normal = character.mitigate_hit(&IncomingHit::new(10000.0, DamageType::OBLIVION).with_critical(50));
+        assert_eq!(normal.raw, 15000.0);
+
+        character.add_buff(BRITTLE_MAJOR_ID, 1);
+        character.recompute_buff_supplemental_state();
+        let brittle = character.mitigate_hit(&IncomingHit::new(10000.0, DamageType::OBLIVION).with_critical(50));
+        assert_eq!(brittle.raw, 17000.0);
+    }
+
+    #[test]
+    fn effective_health_grows_with_mitigation() {
+        let character = tank();
+        let health = character.get_max_health() as f32;
+        assert!((character.get_effective_health(&DamageType::OBLIVION, 0) - health / 0.9).abs() < 1.0);
+        assert!(character.get_effective_health(&DamageType::PHYSICAL, 0) > character.get_effective_health(&DamageType::PHYSICAL, 5948));
+    }
+}
@@ -1,6 +1,20 @@
+pub mod breakdown;
 pub mod character;
+pub mod audit;
+pub mod diff;
+pub mod healing;
+pub mod mitigation;
+pub mod sensitivity;
 mod armour;
+mod block;
+mod cost;
 mod critical;
+mod enchant;
+mod poison;
+mod potion;
 mod power;
 mod resource;
+mod shield;
+mod ultimate;
+mod utility;
 pub mod sets;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use crate::data::item_type::{GearSlot, ItemQuality};
+use crate::data::poison::{POISON_COOLDOWN_MS, POISON_STACK_MAXIMUM, POISONS_BY_ID, Poison};
+use crate::engine::ID;
+use crate::models::player::{ActiveBar, GearPiece, Player};
+
+/// Charges of the poisons equipped in `GearSlot::Poison` and `GearSlot::BackupPoison`. All poisons share one cooldown.
+#[derive(Clone)]
+pub struct Poisons {
+    charges: HashMap<GearSlot, u16>,
+    ready_at_ms: u64,
+}
+
+pub struct PoisonProc {
+    pub slot: GearSlot,
+    pub poison: &'static Poison,
+    /// The last charge was used, so the poison should be unequipped.
+    pub used_up: bool,
+}
+
+impl Poisons {
+    pub fn new() -> Self {
+        Self {
+            charges: HashMap::new(),
+            ready_at_ms: 0,
+        }
+    }
+
+    pub fn is_valid_slot(slot: &GearSlot) -> bool {
+        matches!(slot, GearSlot::Poison | GearSlot::BackupPoison)
+    }
+
+    /// The gear piece for a stack of poison, identified by its id in `data::poison`. Returns None if there is no such poison.
+    pub fn get_gear_piece(id: ID) -> Option<GearPiece> {
+        POISONS_BY_ID.contains_key(&id).then_some(GearPiece {
+            item_id: id,
+            effective_level: 66,
+            gear_trait: None,
+            quality: ItemQuality::Legendary,
+            set_id: None,
+            enchant: None,
+        })
+    }
+
+    /// Tracks `charges` of the poison equipped in the slot. Returns false if the slot is invalid or there are no charges.
+    pub fn set_charges(&mut self, slot: &GearSlot, charges: u16) -> bool {
+        if !Self::is_valid_slot(slot) || charges == 0 {
+            return false;
+        }
+        self.charges.insert(*slot, charges.min(POISON_STACK_MAXIMUM));
+        true
+    }
+
+    /// Charges left in the slot, or None if no poison is equipped there.
+    pub fn get_charges(&self, slot: &GearSlot) -> Option<u16> {
+        self.charges.get(slot).copied()
+    }
+
+    /// Consumes a charge of the poison equipped on the active bar if it is off cooldown.
+    pub fn proc(&mut self, player: &Player, time_ms: u64) -> Option<PoisonProc> {
+        if time_ms < self.ready_at_ms {
+            return None;
+        }
+        let slot = match player.get_active_bar() {
+            ActiveBar::Primary => GearSlot::Poison,
+            ActiveBar::Backup => GearSlot::BackupPoison,
+        };
+        let poison = *POISONS_BY_ID.get(&player.get_gear_piece(&slot)?.item_id)?;
+        let charges = self.charges.get_mut(&slot)?;
+        *charges = charges.checked_sub(1)?;
+        let used_up = *charges == 0;
+        if used_up {
+            self.charges.remove(&slot);
+        }
+        self.ready_at_ms = time_ms + POISON_COOLDOWN_MS;
+        Some(PoisonProc { slot, poison, used_up })
+    }
+}
@@ -0,0 +1,68 @@
+use crate::data::enchant::{get_enchant_jewellery_increase_potion_effectiveness, get_enchant_jewellery_reduce_potion_cooldown};
+use crate::data::item_type::EnchantType;
+use crate::data::potion::{MEDICINAL_USE_DURATION_MULTIPLIER, POTION_COOLDOWN_MS, POTIONS_BY_ID, Potion};
+use crate::data::skill::MEDICINAL_USE_ID;
+use crate::engine::ID;
+use crate::models::player::Player;
+
+pub struct PotionUse {
+    pub potion: &'static Potion,
+    pub duration_ms: u64,
+    /// Health, Magicka, Stamina restored.
+    pub restore: (u32, u32, u32),
+}
+
+#[derive(Clone)]
+pub struct Potions {
+    ready_at_ms: u64,
+}
+
+impl Potions {
+    pub fn new() -> Self {
+        Self {
+            ready_at_ms: 0,
+        }
+    }
+
+    pub fn is_ready(&self, time_ms: u64) -> bool {
+        time_ms >= self.ready_at_ms
+    }
+
+    pub fn get_duration_ms(player: &Player, potion: &Potion) -> u64 {
+        let mut bonus_ms = 0.0;
+        for gear in player.get_active_gear() {
+            if let Some(enchant) = &gear.enchant && enchant.glyph == EnchantType::IncreasePotionEffectiveness {
+                bonus_ms += get_enchant_jewellery_increase_potion_effectiveness(&enchant.effective_level, &enchant.quality) * gear.get_enchant_multiplier() * 1000.0;
+            }
+        }
+        (potion.duration_ms as f32 * Self::get_duration_multiplier(player) + bonus_ms) as u64
+    }
+
+    fn get_duration_multiplier(player: &Player) -> f32 {
+        if player.has_buff(&MEDICINAL_USE_ID) {1.0 + MEDICINAL_USE_DURATION_MULTIPLIER} else {1.0}
+    }
+
+    pub fn get_cooldown_ms(player: &Player) -> u64 {
+        let mut reduction_ms = 0.0;
+        for gear in player.get_active_gear() {
+            if let Some(enchant) = &gear.enchant && enchant.glyph == EnchantType::ReducePotionCooldown {
+                reduction_ms += get_enchant_jewellery_reduce_potion_cooldown(&enchant.effective_level, &enchant.quality) * gear.get_enchant_multiplier() * 1000.0;
+            }
+        }
+        POTION_COOLDOWN_MS.saturating_sub(reduction_ms as u64)
+    }
+
+    /// Drinks a potion from `data::potion` if the potion cooldown is over.
+    pub fn use_potion(&mut self, player: &Player, id: ID, time_ms: u64) -> Option<PotionUse> {
+        let potion = *POTIONS_BY_ID.get(&id)?;
+        if !self.is_ready(time_ms) {
+            return None;
+        }
+        self.ready_at_ms = time_ms + Self::get_cooldown_ms(player);
+        Some(PotionUse {
+            potion,
+            duration_ms: Self::get_duration_ms(player, potion),
+            restore: potion.restore,
+        })
+    }
+}
@@ -1,20 +1,29 @@
 use std::collections::HashMap;
 
 use crate::data::critical_damage::{HEAVY_WEAPONS_ID, TWIN_BLADE_AND_BLUNT_ID};
-use crate::data::item_type::{EnchantType, GearSlot, GearTrait, ItemType};
+use crate::data::item_type::{EnchantType, GearSlot, ItemType};
 use crate::data::power::{POWER_INCREASES_ADDITIVE, POWER_INCREASES_MULTIPLICATIVE, SPELL_POWER_INCREASES_ADDITIVE, SPELL_POWER_INCREASES_MULTIPLICATIVE, WEAPON_POWER_INCREASES_ADDITIVE, WEAPON_POWER_INCREASES_MULTIPLICATIVE};
 use crate::data::sets::{SetBonusType, get_total_bonus};
 use crate::data::skill::{EXPERT_MAGE_ID, SLAYER_ID, SkillLine};
-use crate::data::traits::get_jewelry_infused_value;
 use crate::data::enchant::get_enchant_jewellery_increase_weapon_damage;
+use crate::data::item_type::GearTrait;
+use crate::data::traits::get_jewelry_bloodthirsty_value;
+use crate::data::StatBuff as Buff;
 use crate::engine::{ID, STACKS};
+use crate::engine::player::breakdown::{Operation, StatBreakdown, get_buff_value};
 use crate::models::player::{ActiveBar, Player};
 use crate::models::power::Power as PowerModel;
 
+/// Bloodthirsty jewelry only adds power against enemies below this fraction of their max health.
+pub const BLOODTHIRSTY_HEALTH_THRESHOLD: f32 = 0.9;
 
+#[derive(Clone)]
 pub struct Power {
     pub sources: HashMap<ID, STACKS>,
+    /// Values of buffs which scale with the gear that applied them, used instead of the table value.
+    buff_values: HashMap<ID, u32>,
     gear_source: u32,
+    bloodthirsty: u32,
     weapon: PowerModel,
     spell: PowerModel,
     pub is_dirty: bool,
@@ -24,7 +33,9 @@ impl Power {
     pub fn new() -> Self {
         Self {
             sources: HashMap::new(),
+            buff_values: HashMap::new(),
             gear_source: 0,
+            bloodthirsty: 0,
             weapon: PowerModel::default(),
             spell: PowerModel::default(),
             is_dirty: false,
@@ -43,15 +54,26 @@ impl Power {
     }
 
     pub fn remove_source(&mut self, id: &ID) {
+        self.buff_values.remove(id);
         self.is_dirty = self.sources.remove(id).is_some();
     }
 
+    /// Overrides the value of an additive buff, e.g. Berserker scaled by the glyph which procced it.
+    pub fn set_buff_value(&mut self, id: ID, value: u32) {
+        self.buff_values.insert(id, value);
+        self.is_dirty = true;
+    }
+
+    fn get_additive_value(&self, id: &ID, buff: &Buff, stacks: STACKS) -> u32 {
+        self.buff_values.get(id).copied().unwrap_or((buff.value + buff.value_per_stack * stacks as f64) as u32)
+    }
+
     pub fn refresh(&mut self) {
         self.weapon.reset();
         self.spell.reset();
         for (id, stacks) in &self.sources {
             if let Some(buff) = POWER_INCREASES_ADDITIVE.get(id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = self.get_additive_value(id, buff, *stacks);
                 self.weapon.add_to_additive(value);
                 self.spell.add_to_additive(value);
             } else if let Some(buff) = POWER_INCREASES_MULTIPLICATIVE.get(id) {
@@ -59,13 +81,13 @@ impl Power {
                 self.weapon.add_to_multiplicative(value);
                 self.spell.add_to_multiplicative(value);
             } else if let Some(buff) = WEAPON_POWER_INCREASES_ADDITIVE.get(id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = self.get_additive_value(id, buff, *stacks);
                 self.weapon.add_to_additive(value);
             } else if let Some(buff) = WEAPON_POWER_INCREASES_MULTIPLICATIVE.get(id) {
                 let value = (buff.value + buff.value_per_stack * *stacks as f64) as f32 / 100.0;
                 self.weapon.add_to_multiplicative(value);
             } else if let Some(buff) = SPELL_POWER_INCREASES_ADDITIVE.get(id) {
-                let value = (buff.value + buff.value_per_stack * *stacks as f64) as u32;
+                let value = self.get_additive_value(id, buff, *stacks);
                 self.spell.add_to_additive(value);
             } else if let Some(buff) = SPELL_POWER_INCREASES_MULTIPLICATIVE.get(id) {
                 let value = (buff.value + buff.value_per_stack * *stacks as f64) as f32 / 100.0;
@@ -90,6 +112,39 @@ impl Power {
         self.spell.calculate().max(self.weapon.calculate())
     }
 
+    /// Power against an enemy at `target_health` of their max health, including Bloodthirsty jewelry.
+    pub fn calculate_against(&self, target_health: f32) -> u32 {
+        if target_health >= BLOODTHIRSTY_HEALTH_THRESHOLD {
+            return self.calculate();
+        }
+        let (mut spell, mut weapon) = (self.spell.clone(), self.weapon.clone());
+        spell.set_bloodthirsty(self.bloodthirsty);
+        weapon.set_bloodthirsty(self.bloodthirsty);
+        spell.calculate().max(weapon.calculate())
+    }
+
+    /// Breakdown of whichever of weapon and spell damage is higher.
+    pub fn breakdown(&self) -> StatBreakdown {
+        let use_spell = self.spell.calculate() > self.weapon.calculate();
+        let (power, additive, multiplicative) = if use_spell {
+            (&self.spell, &SPELL_POWER_INCREASES_ADDITIVE, &SPELL_POWER_INCREASES_MULTIPLICATIVE)
+        } else {
+            (&self.weapon, &WEAPON_POWER_INCREASES_ADDITIVE, &WEAPON_POWER_INCREASES_MULTIPLICATIVE)
+        };
+        let mut breakdown = StatBreakdown::new();
+        breakdown.add_base(power.get_base() as f64);
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = POWER_INCREASES_ADDITIVE.get(id).or_else(|| additive.get(id)) {
+                breakdown.add_buff(*id, *stacks, self.get_additive_value(id, buff, *stacks) as f64, Operation::Additive);
+            } else if let Some(buff) = POWER_INCREASES_MULTIPLICATIVE.get(id).or_else(|| multiplicative.get(id)) {
+                breakdown.add_buff(*id, *stacks, (get_buff_value(buff, *stacks) as f32 / 100.0) as f64, Operation::Multiplicative);
+            }
+        }
+        breakdown.add_gear(self.gear_source as f64);
+        let total = power.calculate() as f64;
+        breakdown.finish(total, total)
+    }
+
     pub fn update_from_player(&mut self, player: &Player) {
         self.spell.reset();
         self.weapon.reset();
@@ -135,14 +190,7 @@ impl Power {
         for gear in player.get_active_gear().iter() {
             if let Some(enchant) = &gear.enchant {
                 if matches!(enchant.glyph, EnchantType::IncreasePhysicalDamage | EnchantType::IncreaseSpellDamage) {
-                    let multiplier = if let Some(trait_) = &gear.gear_trait {
-                        match trait_ {
-                            GearTrait::JewelryInfused => get_jewelry_infused_value(&enchant.quality),
-                            _ => 1.0
-                        }
-                    } else {
-                        1.0
-                    };
+                    let multiplier = gear.get_enchant_multiplier();
                     self.gear_source += (get_enchant_jewellery_increase_weapon_damage(&enchant.effective_level, &enchant.quality) * multiplier) as u32;
                 }
             }
@@ -150,6 +198,10 @@ impl Power {
         for set in player.get_active_sets_counts() {
             self.gear_source += get_total_bonus(&set, &SetBonusType::Power(None));
         }
+        self.bloodthirsty = player.get_active_gear().iter()
+            .filter(|gear| gear.gear_trait == Some(GearTrait::JewelryBloodthirsty))
+            .map(|gear| get_jewelry_bloodthirsty_value(&gear.quality) as u32)
+            .sum();
 
         self.refresh();
     }
@@ -1,19 +1,23 @@
 use std::collections::HashMap;
 
-use crate::{data::{item_type::{EnchantType, GearTrait, ItemType}, resource::{DARK_VIGOR, FOOD_BUFFS, JUGGERNAUT, RESOURCE_HEALTH_ADDITIVE, RESOURCE_HEALTH_MULTIPLICATIVE, RESOURCE_MAGICKA_ADDITIVE, RESOURCE_MAGICKA_MULTIPLICATIVE, RESOURCE_STAMINA_ADDITIVE, RESOURCE_STAMINA_MULTIPLICATIVE}, sets::{SetBonusType, get_total_bonus}, skill::{MAGICKA_FLOOD_ID, SkillLine, UNDAUNTED_METTLE_ID}, traits::{get_armor_infused_value, get_jewelry_arcane_value, get_jewelry_healthy_value, get_jewelry_robust_value}}, engine::{ID, STACKS}, models::{player::{Player, get_armour_enchant_multiplier}, resource::{PlayerAttributeType, PlayerMaxResource}}};
+use crate::{data::{item_type::{EnchantType, GearTrait, ItemType}, resource::{DARK_VIGOR, FOOD_BUFFS, FoodBuff, JUGGERNAUT, RESOURCE_HEALTH_ADDITIVE, RESOURCE_HEALTH_MULTIPLICATIVE, RESOURCE_MAGICKA_ADDITIVE, RESOURCE_MAGICKA_MULTIPLICATIVE, RESOURCE_STAMINA_ADDITIVE, RESOURCE_STAMINA_MULTIPLICATIVE}, sets::{SetBonusType, get_total_bonus}, skill::{MAGICKA_FLOOD_ID, SkillLine, UNDAUNTED_METTLE_ID}, traits::{get_armor_invigorating_value, get_jewelry_arcane_value, get_jewelry_healthy_value, get_jewelry_robust_value, get_jewelry_triune_value}}, engine::{ID, STACKS, player::breakdown::{Operation, StatBreakdown, get_buff_rating, get_buff_value}}, models::{player::{Player, get_armour_enchant_multiplier}, resource::{PlayerAttributeType, PlayerMaxResource, PlayerRecovery}}};
 use crate::data::enchant::*;
 
+#[derive(Clone)]
 pub struct Resources {
     sources: HashMap<ID, STACKS>,
-    health: u32,
     max_health: PlayerMaxResource,
-    magicka: u32,
     max_magicka: PlayerMaxResource,
-    stamina: u32,
     max_stamina: PlayerMaxResource,
+    /// Health, magicka and stamina below the maximum. Kept as the amount missing so a new character starts full.
+    missing: (u32, u32, u32),
+    health_recovery: PlayerRecovery,
+    magicka_recovery: PlayerRecovery,
+    stamina_recovery: PlayerRecovery,
     gear_stats: (u32, u32, u32),
+    gear_recovery: (u32, u32, u32),
+    recovery_multiplier: f32,
     attributes: (u8, u8, u8),
-    food: Option<u32>,
     pub is_dirty: bool,
 }
 
@@ -21,15 +25,17 @@ impl Resources {
     pub fn new() -> Self {
         Self {
             sources: HashMap::new(),
-            health: 0,
             max_health: PlayerMaxResource::new(PlayerAttributeType::Health),
-            magicka: 0,
             max_magicka: PlayerMaxResource::new(PlayerAttributeType::Magicka),
-            stamina: 0,
             max_stamina: PlayerMaxResource::new(PlayerAttributeType::Stamina),
+            missing: (0, 0, 0),
+            health_recovery: PlayerRecovery::new(PlayerAttributeType::Health),
+            magicka_recovery: PlayerRecovery::new(PlayerAttributeType::Magicka),
+            stamina_recovery: PlayerRecovery::new(PlayerAttributeType::Stamina),
             gear_stats: (0, 0, 0),
+            gear_recovery: (0, 0, 0),
+            recovery_multiplier: 0.0,
             attributes: (0, 0, 0),
-            food: None,
             is_dirty: false,
         }
     }
@@ -50,7 +56,7 @@ impl Resources {
     }
 
     pub fn is_valid_source(id: &ID) -> bool {
-        RESOURCE_HEALTH_ADDITIVE.get(id).is_some() | RESOURCE_HEALTH_MULTIPLICATIVE.get(id).is_some() | RESOURCE_MAGICKA_ADDITIVE.get(id).is_some() | RESOURCE_MAGICKA_MULTIPLICATIVE.get(id).is_some() | RESOURCE_STAMINA_ADDITIVE.get(&id).is_some() | RESOURCE_STAMINA_MULTIPLICATIVE.get(&id).is_some()
+        FOOD_BUFFS.contains_key(id) | RESOURCE_HEALTH_ADDITIVE.get(id).is_some() | RESOURCE_HEALTH_MULTIPLICATIVE.get(id).is_some() | RESOURCE_MAGICKA_ADDITIVE.get(id).is_some() | RESOURCE_MAGICKA_MULTIPLICATIVE.get(id).is_some() | RESOURCE_STAMINA_ADDITIVE.get(&id).is_some() | RESOURCE_STAMINA_MULTIPLICATIVE.get(&id).is_some()
     }
 
     pub fn refresh(&mut self) {
@@ -59,42 +65,63 @@ impl Resources {
         self.max_stamina.reset();
         for (id, stacks) in &self.sources {
             if let Some(ha) = RESOURCE_HEALTH_ADDITIVE.get(id) {
-                self.max_health.add_to_additive((ha.value + ha.value_per_stack * *stacks as f64) as u32);
+                self.max_health.add_to_additive(get_buff_rating(ha, *stacks));
             }
             if let Some(hm) = RESOURCE_HEALTH_MULTIPLICATIVE.get(id) {
                 self.max_health.add_to_multiplicative((hm.value + hm.value_per_stack * *stacks as f64) as f32 / 100.0);
             }
             if let Some(ma) = RESOURCE_MAGICKA_ADDITIVE.get(id) {
-                self.max_magicka.add_to_additive((ma.value + ma.value_per_stack * *stacks as f64) as u32);
+                self.max_magicka.add_to_additive(get_buff_rating(ma, *stacks));
             }
             if let Some(mm) = RESOURCE_MAGICKA_MULTIPLICATIVE.get(id) {
                 self.max_magicka.add_to_multiplicative((mm.value + mm.value_per_stack * *stacks as f64) as f32 / 100.0);
             }
             if let Some(sa) = RESOURCE_STAMINA_ADDITIVE.get(id) {
-                self.max_stamina.add_to_additive((sa.value + sa.value_per_stack * *stacks as f64) as u32);
+                self.max_stamina.add_to_additive(get_buff_rating(sa, *stacks));
             }
             if let Some(sm) = RESOURCE_STAMINA_MULTIPLICATIVE.get(id) {
                 self.max_stamina.add_to_multiplicative((sm.value + sm.value_per_stack * *stacks as f64) as f32 / 100.0);
             }
         }
-        if let Some(id) = self.food {
-            if let Some(food_buff) = FOOD_BUFFS.get(&id) {
-                if let Some(h) = food_buff.max_health {
-                    self.max_health.add_to_additive(h);
-                };
-                if let Some(m) = food_buff.max_magicka {
-                    self.max_magicka.add_to_additive(m);
-                };
-                if let Some(s) = food_buff.max_stamina {
-                    self.max_stamina.add_to_additive(s);
-                };
-            }
+        self.health_recovery.reset();
+        self.magicka_recovery.reset();
+        self.stamina_recovery.reset();
+        if let Some(food_buff) = self.get_food() {
+            if let Some(h) = food_buff.max_health {
+                self.max_health.add_to_additive(h);
+            };
+            if let Some(m) = food_buff.max_magicka {
+                self.max_magicka.add_to_additive(m);
+            };
+            if let Some(s) = food_buff.max_stamina {
+                self.max_stamina.add_to_additive(s);
+            };
+            if let Some(h) = food_buff.health_recovery {
+                self.health_recovery.add_to_additive(h);
+            };
+            if let Some(m) = food_buff.magicka_recovery {
+                self.magicka_recovery.add_to_additive(m);
+            };
+            if let Some(s) = food_buff.stamina_recovery {
+                self.stamina_recovery.add_to_additive(s);
+            };
         }
         let (ref health, ref magicka, ref stamina) = self.gear_stats;
         self.max_health.add_to_additive(*health);
         self.max_magicka.add_to_additive(*magicka);
         self.max_stamina.add_to_additive(*stamina);
 
+        let (ref health, ref magicka, ref stamina) = self.gear_recovery;
+        self.health_recovery.add_to_additive(*health);
+        self.magicka_recovery.add_to_additive(*magicka);
+        self.stamina_recovery.add_to_additive(*stamina);
+        self.health_recovery.add_to_multiplicative(self.recovery_multiplier);
+        self.magicka_recovery.add_to_multiplicative(self.recovery_multiplier);
+        self.stamina_recovery.add_to_multiplicative(self.recovery_multiplier);
+
+        self.missing.0 = self.missing.0.min(self.get_max_health());
+        self.missing.1 = self.missing.1.min(self.get_max_magicka());
+        self.missing.2 = self.missing.2.min(self.get_max_stamina());
         self.is_dirty = false;
     }
 
@@ -108,9 +135,6 @@ impl Resources {
             if Self::is_valid_source(id) {
                 self.add_source(*id, Some(*stacks))
             }
-            if FOOD_BUFFS.contains_key(id) {
-                self.food = Some(*id);
-            }
         }
         self.add_source(UNDAUNTED_METTLE_ID, Some((player.get_number_of_equipped_item_type(&ItemType::Light) > 0) as u8 + (player.get_number_of_equipped_item_type(&ItemType::Medium) > 0) as u8 + (player.get_number_of_equipped_item_type(&ItemType::Heavy) > 0) as u8));
         let shadow = player.get_number_of_active_skills_from_skill_line(&SkillLine::Shadow);
@@ -122,18 +146,38 @@ impl Resources {
 
         self.gear_stats = (0, 0, 0);
         let (ref mut health, ref mut magicka, ref mut stamina) = self.gear_stats;
+        self.gear_recovery = (0, 0, 0);
+        self.recovery_multiplier = 0.0;
+        let (ref mut health_recovery, ref mut magicka_recovery, ref mut stamina_recovery) = self.gear_recovery;
         for (slot, gear_piece) in player.get_active_gear_with_slots() {
-            let infused_multiplier = match gear_piece.gear_trait {
-                Some(GearTrait::ArmorInfused) => {get_armor_infused_value(&gear_piece.quality)},
-                Some(_) => {1.0},
-                None => {1.0},
-            };
+            let infused_multiplier = gear_piece.get_enchant_multiplier();
             match gear_piece.gear_trait {
                 Some(GearTrait::JewelryArcane) => {*magicka += get_jewelry_arcane_value(&gear_piece.quality) as u32},
                 Some(GearTrait::JewelryHealthy) => {*health += get_jewelry_healthy_value(&gear_piece.quality) as u32},
                 Some(GearTrait::JewelryRobust) => {*stamina += get_jewelry_robust_value(&gear_piece.quality) as u32},
+                Some(GearTrait::JewelryTriune) => {
+                    let (h, m, s) = get_jewelry_triune_value(&gear_piece.quality);
+                    *health += h as u32;
+                    *magicka += m as u32;
+                    *stamina += s as u32;
+                },
+                Some(GearTrait::ArmorInvigorating) => {self.recovery_multiplier += get_armor_invigorating_value(&gear_piece.quality) - 1.0},
                 _ => {},
             }
+            if let Some(enchant) = &gear_piece.enchant {
+                match enchant.glyph {
+                    EnchantType::HealthRegen => *health_recovery += (infused_multiplier * get_enchant_jewellery_recovery(&enchant.effective_level, &enchant.quality)) as u32,
+                    EnchantType::MagickaRegen => *magicka_recovery += (infused_multiplier * get_enchant_jewellery_recovery(&enchant.effective_level, &enchant.quality)) as u32,
+                    EnchantType::StaminaRegen => *stamina_recovery += (infused_multiplier * get_enchant_jewellery_recovery(&enchant.effective_level, &enchant.quality)) as u32,
+                    EnchantType::PrismaticRecovery => {
+                        let (h, m, s) = get_enchant_jewellery_prismatic_recovery(&enchant.effective_level, &enchant.quality);
+                        *health_recovery += (infused_multiplier * h) as u32;
+                        *magicka_recovery += (infused_multiplier * m) as u32;
+                        *stamina_recovery += (infused_multiplier * s) as u32;
+                    },
+                    _ => {},
+                }
+            }
             let slot_multiplier = get_armour_enchant_multiplier(&slot);
             let multi = infused_multiplier * slot_multiplier;
             if let Some(enchant) = &gear_piece.enchant {
@@ -155,6 +199,9 @@ impl Resources {
             *health += get_total_bonus(&set, &SetBonusType::Health(None));
             *magicka += get_total_bonus(&set, &SetBonusType::Magicka(None));
             *stamina += get_total_bonus(&set, &SetBonusType::Stamina(None));
+            *health_recovery += get_total_bonus(&set, &SetBonusType::HealthRecovery(None));
+            *magicka_recovery += get_total_bonus(&set, &SetBonusType::MagickaRecovery(None));
+            *stamina_recovery += get_total_bonus(&set, &SetBonusType::StaminaRecovery(None));
         }
         self.refresh();
     }
@@ -170,4 +217,94 @@ impl Resources {
     pub fn get_max_stamina(&self) -> u32 {
         self.max_stamina.calculate()
     }
-}
\ No newline at end of file
+
+    /// Breakdown of the maximum of `resource`. Food shows as its buff ID.
+    pub fn breakdown_max(&self, resource: &PlayerAttributeType) -> StatBreakdown {
+        let (max, additive, multiplicative, gear) = match resource {
+            PlayerAttributeType::Health => (&self.max_health, &RESOURCE_HEALTH_ADDITIVE, &RESOURCE_HEALTH_MULTIPLICATIVE, self.gear_stats.0),
+            PlayerAttributeType::Magicka => (&self.max_magicka, &RESOURCE_MAGICKA_ADDITIVE, &RESOURCE_MAGICKA_MULTIPLICATIVE, self.gear_stats.1),
+            PlayerAttributeType::Stamina => (&self.max_stamina, &RESOURCE_STAMINA_ADDITIVE, &RESOURCE_STAMINA_MULTIPLICATIVE, self.gear_stats.2),
+        };
+        let mut breakdown = StatBreakdown::new();
+        breakdown.add_base(max.get_base() as f64);
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = additive.get(id) {
+                breakdown.add_buff(*id, *stacks, get_buff_rating(buff, *stacks) as f64, Operation::Additive);
+            }
+            if let Some(buff) = multiplicative.get(id) {
+                breakdown.add_buff(*id, *stacks, (get_buff_value(buff, *stacks) as f32 / 100.0) as f64, Operation::Multiplicative);
+            }
+        }
+        if let Some(food_buff) = self.get_food() {
+            let value = match resource {
+                PlayerAttributeType::Health => food_buff.max_health,
+                PlayerAttributeType::Magicka => food_buff.max_magicka,
+                PlayerAttributeType::Stamina => food_buff.max_stamina,
+            };
+            if let Some(value) = value {
+                breakdown.add_buff(food_buff.id, 1, value as f64, Operation::Additive);
+            }
+        }
+        breakdown.add_gear(gear as f64);
+        let total = max.calculate() as f64;
+        breakdown.finish(total, total)
+    }
+
+    fn get_food(&self) -> Option<&'static FoodBuff> {
+        self.sources.keys().find_map(|id| FOOD_BUFFS.get(id).copied())
+    }
+
+    pub fn get_health_recovery(&self) -> u32 {
+        self.health_recovery.calculate()
+    }
+
+    pub fn get_magicka_recovery(&self) -> u32 {
+        self.magicka_recovery.calculate()
+    }
+
+    pub fn get_stamina_recovery(&self) -> u32 {
+        self.stamina_recovery.calculate()
+    }
+
+    fn get_missing_mut(&mut self, resource: &PlayerAttributeType) -> &mut u32 {
+        match resource {
+            PlayerAttributeType::Health => &mut self.missing.0,
+            PlayerAttributeType::Magicka => &mut self.missing.1,
+            PlayerAttributeType::Stamina => &mut self.missing.2,
+        }
+    }
+
+    /// Current amount of `resource`.
+    pub fn get_current(&self, resource: &PlayerAttributeType) -> u32 {
+        match resource {
+            PlayerAttributeType::Health => self.get_max_health().saturating_sub(self.missing.0),
+            PlayerAttributeType::Magicka => self.get_max_magicka().saturating_sub(self.missing.1),
+            PlayerAttributeType::Stamina => self.get_max_stamina().saturating_sub(self.missing.2),
+        }
+    }
+
+    /// Spends `amount` of `resource` if there is enough of it. Returns false, spending nothing, otherwise.
+    pub fn spend(&mut self, resource: &PlayerAttributeType, amount: u32) -> bool {
+        if self.get_current(resource) < amount {
+            return false;
+        }
+        *self.get_missing_mut(resource) += amount;
+        true
+    }
+
+    /// Removes up to `amount` of `resource`, returning how much was removed.
+    pub fn drain(&mut self, resource: &PlayerAttributeType, amount: u32) -> u32 {
+        let drained = amount.min(self.get_current(resource));
+        *self.get_missing_mut(resource) += drained;
+        drained
+    }
+
+    pub fn restore(&mut self, resource: &PlayerAttributeType, amount: u32) {
+        let missing = self.get_missing_mut(resource);
+        *missing = missing.saturating_sub(amount);
+    }
+
+    pub fn fill(&mut self) {
+        self.missing = (0, 0, 0);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::engine::ID;
+use crate::engine::player::character::Character;
+use crate::engine::world::damage::mitigate;
+use crate::engine::world::target::Target;
+use crate::models::critical::CriticalDamage as CriticalDamageModel;
+use crate::models::damage::DamageType;
+use crate::models::resource::PlayerAttributeType;
+
+/// One hit of a rotation. Tooltip damage is `resource_coefficient * max resource + power_coefficient * power`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationHit {
+    pub ability_id: ID,
+    pub damage_type: DamageType,
+    pub resource: PlayerAttributeType,
+    pub resource_coefficient: f32,
+    pub power_coefficient: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rotation {
+    pub hits: Vec<RotationHit>,
+    pub duration_ms: u64,
+}
+
+impl Rotation {
+    pub fn new(duration_ms: u64) -> Self {
+        Self {
+            hits: Vec::new(),
+            duration_ms,
+        }
+    }
+
+    pub fn add_hit(&mut self, hit: RotationHit) {
+        self.hits.push(hit);
+    }
+}
+
+/// The stats a rotation's damage depends on, uncapped so increases can be applied before the caps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OffensiveStats {
+    power: f32,
+    critical_chance: f32,
+    critical_damage: u16,
+    penetration: u32,
+    max_resources: (f32, f32, f32),
+}
+
+impl OffensiveStats {
+    fn from_character(character: &Character) -> Self {
+        let critical_chance = character.get_critical_chance_breakdown();
+        let critical_damage = character.get_critical_damage_done_breakdown();
+        Self {
+            power: character.get_power() as f32,
+            critical_chance: critical_chance.capped_from.unwrap_or(critical_chance.total) as f32,
+            critical_damage: critical_damage.capped_from.unwrap_or(critical_damage.total) as u16,
+            penetration: character.get_penetration(),
+            max_resources: (character.get_max_health() as f32, character.get_max_magicka() as f32, character.get_max_stamina() as f32),
+        }
+    }
+
+    fn get_max_resource(&self, resource: &PlayerAttributeType) -> f32 {
+        match resource {
+            PlayerAttributeType::Health => self.max_resources.0,
+            PlayerAttributeType::Magicka => self.max_resources.1,
+            PlayerAttributeType::Stamina => self.max_resources.2,
+        }
+    }
+
+    /// Average damage multiplier from critical strikes, after the critical chance and damage caps.
+    fn get_critical_multiplier(&self) -> f32 {
+        let mut critical_damage = CriticalDamageModel::default();
+        critical_damage.add_percent(self.critical_damage.saturating_sub(critical_damage.get_base()));
+        1.0 + self.critical_chance.min(1.0) * critical_damage.calculate() as f32 / 100.0
+    }
+
+    fn get_dps(&self, target: &Target, rotation: &Rotation) -> f64 {
+        if rotation.duration_ms == 0 {
+            return 0.0;
+        }
+        let armour = target.get_armour();
+        let multiplier = self.get_critical_multiplier() * target.get_damage_taken_multiplier();
+        let damage: f32 = rotation.hits.iter().map(|hit| {
+            let tooltip = hit.resource_coefficient * self.get_max_resource(&hit.resource) + hit.power_coefficient * self.power;
+            mitigate(tooltip * multiplier, &hit.damage_type, armour, self.penetration)
+        }).sum();
+        damage as f64 * 1000.0 / rotation.duration_ms as f64
+    }
+}
+
+/// DPS gained from a fixed increase of each stat, against one target. A stat at its cap gains nothing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatSensitivity {
+    pub dps: f64,
+    /// Per +1% critical damage.
+    pub critical_damage: f64,
+    /// Per +1% critical chance.
+    pub critical_chance: f64,
+    /// Per +100 weapon and spell damage.
+    pub power: f64,
+    /// Per +1000 penetration.
+    pub penetration: f64,
+    /// Per +1000 of the max resource each hit scales from.
+    pub max_resource: f64,
+}
+
+impl StatSensitivity {
+    pub fn new(character: &Character, target: &Target, rotation: &Rotation) -> Self {
+        let stats = OffensiveStats::from_character(character);
+        let dps = stats.get_dps(target, rotation);
+        let gain = |change: &dyn Fn(&mut OffensiveStats)| {
+            let mut changed = stats;
+            change(&mut changed);
+            changed.get_dps(target, rotation) - dps
+        };
+        Self {
+            dps,
+            critical_damage: gain(&|stats| stats.critical_damage += 1),
+            critical_chance: gain(&|stats| stats.critical_chance += 0.01),
+            power: gain(&|stats| stats.power += 100.0),
+            penetration: gain(&|stats| stats.penetration += 1000),
+            max_resource: gain(&|stats| {
+                stats.max_resources.0 += 1000.0;
+                stats.max_resources.1 += 1000.0;
+                stats.max_resources.2 += 1000.0;
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::critical_damage::{ADVANCED_SPECIES_ID, FELINE_AMBUSH_ID, HEMORRHAGE_PASSIVE_ID, PIERCING_SPEAR_ID, SUL_XAN_SOULBOUND};
+    use crate::data::major_minor::{FORCE_MAJOR_ID, FORCE_MINOR_ID};
+
+    use super::*;
+
+    fn rotation() -> Rotation {
+        let mut rotation = Rotation::new(1000);
+        rotation.add_hit(RotationHit {
+            ability_id: 0,
+            damage_type: DamageType::MAGIC,
+            resource: PlayerAttributeType::Magicka,
+            resource_coefficient: 0.1,
+            power_coefficient: 1.05,
+        });
+        rotation
+    }
+
+    #[test]
+    fn every_stat_gains_below_caps() {
+        let mut character = Character::new(0);
+        character.recompute_all_supplemental_state();
+
+        let sensitivity = StatSensitivity::new(&character, &Target::with_armour(1, 10000), &rotation());
+        assert!(sensitivity.dps > 0.0);
+        assert!(sensitivity.critical_damage > 0.0);
+        assert!(sensitivity.critical_chance > 0.0);
+        assert!(sensitivity.power > 0.0);
+        assert!(sensitivity.penetration > 0.0);
+        assert!(sensitivity.max_resource > 0.0);
+    }
+
+    #[test]
+    fn capped_stats_gain_nothing() {
+        let mut character = Character::new(0);
+        for buff_id in [FORCE_MAJOR_ID, FORCE_MINOR_ID, FELINE_AMBUSH_ID, HEMORRHAGE_PASSIVE_ID, PIERCING_SPEAR_ID, SUL_XAN_SOULBOUND] {
+            character.add_buff(buff_id, 1);
+        }
+        character.add_buff(ADVANCED_SPECIES_ID, 3);
+        character.recompute_all_supplemental_state();
+        assert_eq!(character.get_critical_damage_done(), 125);
+
+        let sensitivity = StatSensitivity::new(&character, &Target::with_armour(1, 0), &rotation());
+        assert_eq!(sensitivity.critical_damage, 0.0);
+        assert_eq!(sensitivity.penetration, 0.0);
+        assert!(sensitivity.power > 0.0);
+    }
+}
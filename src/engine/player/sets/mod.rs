@@ -3,8 +3,12 @@ use std::collections::HashMap;
 use crate::engine::world::event::SetDescriptor;
 use phf::phf_map;
 
+mod oakensoul;
 mod velothi;
 
+pub use oakensoul::OAKENSOUL_RING_ID;
+
 pub static SET_REGISTRY_MAP: phf::Map<u16, &'static SetDescriptor> = phf_map! {
+    658u16 => &oakensoul::OAKENSOUL_DESCRIPTOR,
     694u16 => &velothi::VELOTHI_DESCRIPTOR,
 };
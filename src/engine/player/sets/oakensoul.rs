@@ -0,0 +1,57 @@
+use crate::data::major_minor::*;
+
+use crate::engine::ID;
+use crate::engine::world::event::{Event, SetDescriptor, SetInstance, World};
+
+pub const OAKENSOUL_RING_ID: u16 = 658;
+
+/// Every Major buff granted while the ring is worn.
+const OAKENSOUL_BUFFS: &[ID] = &[
+    BERSERK_MAJOR_ID,
+    BRUTALITY_MAJOR_ID,
+    COURAGE_MAJOR_ID,
+    ENDURANCE_MAJOR_ID,
+    EVASION_MAJOR_ID,
+    EXPEDITION_MAJOR_ID,
+    FORTITUDE_MAJOR_ID,
+    HEROISM_MAJOR_ID,
+    INTELLECT_MAJOR_ID,
+    MENDING_MAJOR_ID,
+    PROPHECY_MAJOR_ID,
+    RESOLVE_MAJOR_ID,
+    SAVAGERY_MAJOR_ID,
+    SORCERY_MAJOR_ID,
+    VITALITY_MAJOR_ID,
+];
+
+/// Bar swapping is blocked by `Character::swap_bars` while the ring is worn.
+pub struct OakensoulInstance;
+
+impl OakensoulInstance {
+    pub fn new() -> Self { OakensoulInstance }
+}
+
+impl SetInstance for OakensoulInstance {
+    fn on_activate(&mut self, owner: ID, world: &mut dyn World) {
+        for buff_id in OAKENSOUL_BUFFS {
+            world.add_set_buff(owner, OAKENSOUL_RING_ID, *buff_id, 1);
+        }
+    }
+
+    fn on_deactivate(&mut self, owner: ID, world: &mut dyn World) {
+        for buff_id in OAKENSOUL_BUFFS {
+            world.remove_set_buff(owner, OAKENSOUL_RING_ID, *buff_id);
+        }
+    }
+
+    fn on_event(&mut self, _owner: ID, _event: &Event, _world: &mut dyn World) {
+        //
+    }
+}
+
+pub static OAKENSOUL_DESCRIPTOR: SetDescriptor = SetDescriptor {
+    id: OAKENSOUL_RING_ID,
+    min_pieces: 1,
+    priority: 50,
+    instance_factory: || Box::new(OakensoulInstance::new()),
+};
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::data::damage_modifier::SHIELD_STRENGTH_BY_ID;
+use crate::engine::{ID, STACKS};
+use crate::engine::player::breakdown::get_buff_value;
+use crate::models::player::Player;
+
+/// A single damage shield can absorb at most this fraction of the player's max health.
+pub const SHIELD_CAP_OF_MAX_HEALTH: f32 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shield {
+    pub id: ID,
+    pub remaining: f32,
+    pub expires_at_ms: Option<u64>,
+}
+
+/// Damage shields on the player, consumed oldest first. Reapplying a shield replaces it.
+#[derive(Clone)]
+pub struct Shields {
+    sources: HashMap<ID, STACKS>,
+    shields: Vec<Shield>,
+    strength: f32,
+    absorbed: f32,
+    pub is_dirty: bool,
+}
+
+impl Shields {
+    pub fn new() -> Self {
+        Self {
+            sources: HashMap::new(),
+            shields: Vec::new(),
+            strength: 0.0,
+            absorbed: 0.0,
+            is_dirty: false,
+        }
+    }
+
+    pub fn add_source(&mut self, id: ID, stacks: Option<STACKS>) {
+        self.sources.insert(id, stacks.unwrap_or(1));
+        self.is_dirty = true;
+    }
+
+    pub fn add_source_checked(&mut self, id: ID, stacks: Option<STACKS>) {
+        if Self::is_valid_source(&id) {
+            self.add_source(id, stacks);
+        }
+    }
+
+    pub fn remove_source(&mut self, id: &ID) {
+        self.is_dirty = self.sources.remove(id).is_some();
+    }
+
+    pub fn is_valid_source(id: &ID) -> bool {
+        SHIELD_STRENGTH_BY_ID.get(id).is_some()
+    }
+
+    pub fn refresh(&mut self) {
+        self.strength = 0.0;
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = SHIELD_STRENGTH_BY_ID.get(id) {
+                self.strength += get_buff_value(buff, *stacks) as f32 / 100.0;
+            }
+        }
+        self.is_dirty = false;
+    }
+
+    /// Multiplier on the strength of new shields.
+    pub fn get_strength_multiplier(&self) -> f32 {
+        1.0 + self.strength
+    }
+
+    /// Adds a shield of `amount` before strength modifiers, capped by `max_health`. Returns the strength of the shield.
+    pub fn add(&mut self, id: ID, amount: f32, max_health: u32, expires_at_ms: Option<u64>) -> f32 {
+        let remaining = (amount * self.get_strength_multiplier()).min(max_health as f32 * SHIELD_CAP_OF_MAX_HEALTH);
+        self.shields.retain(|shield| shield.id != id);
+        self.shields.push(Shield { id, remaining, expires_at_ms });
+        remaining
+    }
+
+    pub fn remove(&mut self, id: &ID) {
+        self.shields.retain(|shield| &shield.id != id);
+    }
+
+    /// Removes every shield that has run out by `time_ms`.
+    pub fn expire(&mut self, time_ms: u64) {
+        self.shields.retain(|shield| shield.expires_at_ms.is_none_or(|expires_at| expires_at > time_ms));
+    }
+
+    pub fn get_shields(&self) -> &Vec<Shield> {
+        &self.shields
+    }
+
+    pub fn get_total(&self) -> f32 {
+        self.shields.iter().map(|shield| shield.remaining).sum()
+    }
+
+    /// Total damage absorbed by shields so far.
+    pub fn get_absorbed(&self) -> f32 {
+        self.absorbed
+    }
+
+    /// Consumes up to `amount` from the shields, returning how much was absorbed. Depleted shields are removed.
+    pub fn absorb(&mut self, amount: f32) -> f32 {
+        let mut left = amount.max(0.0);
+        for shield in self.shields.iter_mut() {
+            let taken = left.min(shield.remaining);
+            shield.remaining -= taken;
+            left -= taken;
+        }
+        self.shields.retain(|shield| shield.remaining > 0.0);
+        let absorbed = amount.max(0.0) - left;
+        self.absorbed += absorbed;
+        absorbed
+    }
+
+    pub fn update_from_player(&mut self, player: &Player) {
+        self.sources.clear();
+        for (id, stacks) in player.get_buffs() {
+            if Self::is_valid_source(id) {
+                self.add_source(*id, Some(*stacks));
+            }
+        }
+        self.refresh();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::damage_modifier::BASTION;
+    use crate::engine::player::character::Character;
+    use crate::engine::player::mitigation::IncomingHit;
+    use crate::models::damage::DamageType;
+
+    use super::*;
+
+    #[test]
+    fn shields_absorb_before_health_oldest_first() {
+        let mut character = Character::new(0);
+        character.recompute_all_supplemental_state();
+        let health = character.get_max_health();
+        character.add_shield(1, 1000.0, None);
+        character.add_shield(2, 2000.0, None);
+
+        let hit = character.take_hit(&IncomingHit::new(1500.0, DamageType::OBLIVION));
+        assert_eq!(hit.absorbed, 1500.0);
+        assert_eq!(hit.taken, 0.0);
+        assert_eq!(character.get_health(), health);
+        assert_eq!(character.get_shields(), &vec![Shield { id: 2, remaining: 1500.0, expires_at_ms: None }]);
+
+        let hit = character.take_hit(&IncomingHit::new(2000.0, DamageType::OBLIVION));
+        assert_eq!(hit.absorbed, 1500.0);
+        assert_eq!(character.get_health(), health - 500);
+        assert_eq!(character.get_shield(), 0.0);
+        assert_eq!(character.get_damage_absorbed(), 3000.0);
+    }
+
+    #[test]
+    fn shield_strength_applies_before_the_max_health_cap() {
+        let mut character = Character::new(0);
+        character.add_buff(BASTION.id, 5);
+        character.recompute_all_supplemental_state();
+
+        assert_eq!(character.add_shield(1, 1000.0, None), 1150.0);
+        let cap = character.get_max_health() as f32 * SHIELD_CAP_OF_MAX_HEALTH;
+        assert_eq!(character.add_shield(1, 1_000_000.0, None), cap);
+        assert_eq!(character.get_shield(), cap);
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::data::ultimate::{ULTIMATE_COST_REDUCTION_BY_ID, ULTIMATE_GENERATION_BY_ID, ULTIMATE_MAX, ULTIMATE_ON_POTION_BY_ID};
+use crate::engine::{ID, STACKS};
+use crate::engine::player::breakdown::get_buff_value;
+use crate::models::player::Player;
+
+/// The player's ultimate points, and the buffs and passives which generate ultimate or reduce ultimate costs.
+#[derive(Clone)]
+pub struct Ultimate {
+    sources: HashMap<ID, STACKS>,
+    current: u32,
+    /// Accumulated chance of an additional point, so that over many gains the extra points match the chance.
+    bonus_meter: f32,
+    generation: u32,
+    on_potion: u32,
+    cost_reduction: f32,
+    pub is_dirty: bool,
+}
+
+impl Ultimate {
+    pub fn new() -> Self {
+        Self {
+            sources: HashMap::new(),
+            current: 0,
+            bonus_meter: 0.0,
+            generation: 0,
+            on_potion: 0,
+            cost_reduction: 0.0,
+            is_dirty: false,
+        }
+    }
+
+    pub fn add_source(&mut self, id: ID, stacks: Option<STACKS>) {
+        self.sources.insert(id, stacks.unwrap_or(1));
+        self.is_dirty = true;
+    }
+
+    pub fn add_source_checked(&mut self, id: ID, stacks: Option<STACKS>) {
+        if Self::is_valid_source(&id) {
+            self.add_source(id, stacks);
+        }
+    }
+
+    pub fn remove_source(&mut self, id: &ID) {
+        self.is_dirty = self.sources.remove(id).is_some();
+    }
+
+    pub fn is_valid_source(id: &ID) -> bool {
+        ULTIMATE_GENERATION_BY_ID.get(id).is_some() | ULTIMATE_ON_POTION_BY_ID.get(id).is_some() | ULTIMATE_COST_REDUCTION_BY_ID.get(id).is_some()
+    }
+
+    pub fn refresh(&mut self) {
+        self.generation = 0;
+        self.on_potion = 0;
+        self.cost_reduction = 0.0;
+        for (id, stacks) in &self.sources {
+            if let Some(buff) = ULTIMATE_GENERATION_BY_ID.get(id) {
+                self.generation += get_buff_value(buff, *stacks) as u32;
+            }
+            if let Some(buff) = ULTIMATE_ON_POTION_BY_ID.get(id) {
+                self.on_potion += get_buff_value(buff, *stacks) as u32;
+            }
+            if let Some(buff) = ULTIMATE_COST_REDUCTION_BY_ID.get(id) {
+                self.cost_reduction += get_buff_value(buff, *stacks) as f32 / 100.0;
+            }
+        }
+        self.is_dirty = false;
+    }
+
+    pub fn get_current(&self) -> u32 {
+        self.current
+    }
+
+    /// Ultimate gained every generation interval from buffs such as Heroism.
+    pub fn get_generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Ultimate gained on drinking a potion.
+    pub fn get_on_potion(&self) -> u32 {
+        self.on_potion
+    }
+
+    pub fn get_cost(&self, base_cost: u32) -> u32 {
+        (base_cost as f32 * (1.0 - self.cost_reduction).max(0.0)).round() as u32
+    }
+
+    /// Gains `amount` ultimate, and an additional point whenever `bonus_chance` has added up to one. Returns the ultimate gained.
+    pub fn gain(&mut self, amount: u32, bonus_chance: f32) -> u32 {
+        if amount == 0 {return 0}
+        let mut gained = amount;
+        self.bonus_meter += bonus_chance;
+        if self.bonus_meter >= 1.0 {
+            self.bonus_meter -= 1.0;
+            gained += 1;
+        }
+        let gained = gained.min(ULTIMATE_MAX - self.current);
+        self.current += gained;
+        gained
+    }
+
+    /// Spends `cost` ultimate if the player has enough.
+    pub fn spend(&mut self, cost: u32) -> bool {
+        if self.current < cost {return false}
+        self.current -= cost;
+        true
+    }
+
+    pub fn update_from_player(&mut self, player: &Player) {
+        self.sources.clear();
+        for (id, stacks) in player.get_buffs() {
+            if Self::is_valid_source(id) {
+                self.add_source(*id, Some(*stacks));
+            }
+        }
+        self.refresh();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::skill::POWER_STONE_ID;
+    use crate::data::ultimate::ULTIMATE_COST_BY_ID;
+    use crate::data::skill::DAWNBREAKER_ID;
+    use crate::engine::player::character::Character;
+
+    use super::*;
+
+    #[test]
+    fn ultimate_is_capped_and_decisive_adds_bonus_points() {
+        let mut ultimate = Ultimate::new();
+        assert_eq!(ultimate.gain(3, 0.5), 3);
+        assert_eq!(ultimate.gain(3, 0.5), 4);
+        assert_eq!(ultimate.get_current(), 7);
+        assert!(!ultimate.spend(8));
+        assert!(ultimate.spend(7));
+
+        assert_eq!(ultimate.gain(1000, 0.0), ULTIMATE_MAX);
+        assert_eq!(ultimate.gain(3, 0.0), 0);
+    }
+
+    #[test]
+    fn power_stone_reduces_ultimate_costs() {
+        let mut character = Character::new(0);
+        character.recompute_all_supplemental_state();
+        assert_eq!(character.get_ultimate_cost(&DAWNBREAKER_ID), Some(125));
+        assert_eq!(character.get_ultimate_cost(&0), None);
+
+        character.add_buff(POWER_STONE_ID, 1);
+        character.recompute_buff_supplemental_state();
+        let base = ULTIMATE_COST_BY_ID[&DAWNBREAKER_ID];
+        assert_eq!(character.get_ultimate_cost(&DAWNBREAKER_ID), Some((base as f32 * 0.85).round() as u32));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::data::item_type::GearTrait;
+use crate::data::sets::{SetBonusType, get_total_bonus};
+use crate::data::traits::{get_armor_training_value, get_jewelry_harmony_value, get_jewelry_swift_value};
+use crate::models::player::Player;
+
+/// Stats from gear which do not feed into damage or resource calculations directly.
+#[derive(Clone)]
+pub struct Utility {
+    synergy_damage: u32,
+    movement_speed: f32,
+    ultimate_generation_chance: f32,
+    status_effect_chance: f32,
+    experience: f32,
+    healing_done: f32,
+}
+
+impl Utility {
+    pub fn new() -> Self {
+        Self {
+            synergy_damage: 0,
+            movement_speed: 0.0,
+            ultimate_generation_chance: 0.0,
+            status_effect_chance: 0.0,
+            experience: 0.0,
+            healing_done: 0.0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.synergy_damage = 0;
+        self.movement_speed = 0.0;
+        self.ultimate_generation_chance = 0.0;
+        self.status_effect_chance = 0.0;
+        self.experience = 0.0;
+        self.healing_done = 0.0;
+    }
+
+    /// Flat damage added to synergies activated by the player.
+    pub fn get_synergy_damage(&self) -> u32 {
+        self.synergy_damage
+    }
+
+    pub fn get_movement_speed(&self) -> f32 {
+        self.movement_speed
+    }
+
+    /// Chance to gain an additional point of ultimate whenever ultimate is gained.
+    pub fn get_ultimate_generation_chance(&self) -> f32 {
+        self.ultimate_generation_chance
+    }
+
+    pub fn get_status_effect_chance(&self) -> f32 {
+        self.status_effect_chance
+    }
+
+    pub fn get_experience(&self) -> f32 {
+        self.experience
+    }
+
+    pub fn get_healing_done(&self) -> f32 {
+        self.healing_done
+    }
+
+    pub fn update_from_player(&mut self, player: &Player) {
+        self.reset();
+        for set in player.get_active_sets_counts() {
+            self.healing_done += get_total_bonus(&set, &SetBonusType::HealingDone(None)) as f32 / 100.0;
+        }
+        for gear_piece in player.get_active_gear() {
+            let weapon_bonus = gear_piece.get_trait_value().map_or(0.0, |value| value - 1.0);
+            match gear_piece.gear_trait {
+                Some(GearTrait::JewelryHarmony) => self.synergy_damage += get_jewelry_harmony_value(&gear_piece.quality) as u32,
+                Some(GearTrait::JewelrySwift) => self.movement_speed += get_jewelry_swift_value(&gear_piece.quality) - 1.0,
+                Some(GearTrait::WeaponDecisive) => self.ultimate_generation_chance += weapon_bonus,
+                Some(GearTrait::WeaponCharged) => self.status_effect_chance += weapon_bonus,
+                Some(GearTrait::WeaponPowered) => self.healing_done += weapon_bonus,
+                Some(GearTrait::WeaponTraining) => self.experience += weapon_bonus,
+                Some(GearTrait::ArmorTraining) => self.experience += get_armor_training_value(&gear_piece.quality) - 1.0,
+                _ => {},
+            }
+        }
+    }
+}
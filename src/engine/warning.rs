@@ -0,0 +1,18 @@
+use crate::engine::ID;
+
+/// Something in the input that the game would not allow, which the simulator has resolved on a best guess.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Warning {
+    /// Only one food buff can be active, so `added` replaced `replaced`.
+    MultipleFoodBuffs { replaced: ID, added: ID },
+    /// `skill_id` was used without enough ultimate, so it was not cast.
+    NotEnoughUltimate { skill_id: ID, cost: u32, ultimate: u32 },
+    /// `skill_id` was used while `casting` was still being cast or channelled, so it was not cast.
+    SkillUsedWhileCasting { skill_id: ID, casting: ID },
+    /// `skill_id` was used with `remaining_ms` left on the global cooldown, so it was not cast.
+    GlobalCooldown { skill_id: ID, remaining_ms: u64 },
+    /// `skill_id` was used with `remaining_ms` left on its cooldown, so it was not cast.
+    SkillOnCooldown { skill_id: ID, remaining_ms: u64 },
+    /// Bars were swapped while `set_id` was equipped, which only allows a single bar, so the swap was ignored.
+    BarSwapBlocked { set_id: u16 },
+}
@@ -0,0 +1,44 @@
+use crate::data::cast::CastInfo;
+use crate::engine::world::event::UnitId;
+
+/// A skill being cast or channelled, which keeps the caster from using other skills until it ends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveCast {
+    pub caster: UnitId,
+    pub target: UnitId,
+    pub info: &'static CastInfo,
+    pub started_at_ms: u64,
+    pub next_tick_ms: u64,
+    pub ticks_left: u32,
+}
+
+impl ActiveCast {
+    pub fn new(caster: UnitId, target: UnitId, info: &'static CastInfo, time_ms: u64) -> Self {
+        Self {
+            caster,
+            target,
+            info,
+            started_at_ms: time_ms,
+            next_tick_ms: if info.channeled {time_ms} else {time_ms + info.duration_ms},
+            ticks_left: info.get_ticks(),
+        }
+    }
+
+    pub fn get_ends_at_ms(&self) -> u64 {
+        self.started_at_ms + self.info.duration_ms
+    }
+
+    /// Whether another hit is due by `time_ms`.
+    pub fn is_due(&self, time_ms: u64) -> bool {
+        self.ticks_left > 0 && self.next_tick_ms <= time_ms
+    }
+
+    pub fn tick(&mut self) {
+        self.ticks_left -= 1;
+        self.next_tick_ms += self.info.frequency_ms;
+    }
+
+    pub fn has_ended(&self, time_ms: u64) -> bool {
+        self.ticks_left == 0 && self.get_ends_at_ms() <= time_ms
+    }
+}
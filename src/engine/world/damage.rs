@@ -0,0 +1,93 @@
+use crate::data::attack::Attack;
+use crate::data::damage_modifier::{AOE_DAMAGE_DONE_BY_ID, DAMAGE_DONE_BY_ID, SINGLE_TARGET_DAMAGE_DONE_BY_ID};
+use crate::data::pet::PET_DAMAGE_DONE_BY_ID;
+use crate::data::skill_damage::SkillDamage;
+use crate::engine::ID;
+use crate::engine::player::breakdown::get_buff_value;
+use crate::engine::player::character::Character;
+use crate::engine::world::target::Target;
+use crate::engine::world::event::UnitId;
+use crate::models::damage::{DamageSize, DamageType};
+use crate::models::damage_done::DamageDone;
+use crate::models::damage_taken::Resistance;
+
+#[derive(Debug, PartialEq)]
+pub struct DamageRecord {
+    pub time_ms: u64,
+    pub source: UnitId,
+    pub target: UnitId,
+    pub ability_id: ID,
+    pub damage_type: DamageType,
+    pub amount: f32,
+    /// Whether the target was in the execute phase when hit.
+    pub execute: bool,
+}
+
+/// Damage per second before and during the execute phase. A phase with no time elapsed has no damage per second.
+#[derive(Debug, PartialEq)]
+pub struct PhaseDps {
+    pub pre_execute: f32,
+    pub execute: f32,
+}
+
+/// Damage remaining after the target's armour, less the attacker's penetration. Oblivion damage ignores armour.
+pub fn mitigate(amount: f32, damage_type: &DamageType, armour: u32, penetration: u32) -> f32 {
+    if *damage_type == DamageType::OBLIVION {
+        return amount;
+    }
+    let mut resistance = Resistance::new();
+    resistance.add_to_additive(armour.saturating_sub(penetration));
+    amount * (1.0 - resistance.calculate())
+}
+
+/// Tooltip damage of `skill`, per tick for damage over time, from the caster's current stats.
+pub fn get_skill_tooltip(skill: &SkillDamage, caster: &Character) -> f32 {
+    skill.resource_coefficient * caster.get_max_resource(&skill.resource) as f32 + skill.power_coefficient * caster.get_power() as f32
+}
+
+/// Damage done modifiers from the caster's buffs.
+pub fn get_damage_done(caster: &Character) -> DamageDone {
+    let mut damage_done = DamageDone::new();
+    for (id, stacks) in caster.get_buffs() {
+        if let Some(buff) = DAMAGE_DONE_BY_ID.get(id) {
+            damage_done.add_to_global(get_buff_value(buff, *stacks) as f32);
+        }
+        if let Some(buff) = SINGLE_TARGET_DAMAGE_DONE_BY_ID.get(id) {
+            damage_done.add_to_single_target(get_buff_value(buff, *stacks) as f32);
+        }
+        if let Some(buff) = AOE_DAMAGE_DONE_BY_ID.get(id) {
+            damage_done.add_to_aoe(get_buff_value(buff, *stacks) as f32);
+        }
+        if let Some(buff) = PET_DAMAGE_DONE_BY_ID.get(id) {
+            damage_done.add_to_pet(get_buff_value(buff, *stacks) as f32);
+        }
+    }
+    damage_done
+}
+
+/// Damage of `skill` against `target`, including Bloodthirsty and execute bonuses for the target's health and the caster's damage done for the skill's size.
+pub fn get_skill_damage(skill: &SkillDamage, caster: &Character, target: &Target) -> f32 {
+    get_skill_damage_with_multiplier(skill, caster, target, get_damage_done(caster).get_multiplier(&skill.size))
+}
+
+/// Damage of a pet's `attack` against `target`, from its owner's stats and damage done including pet damage done.
+pub fn get_pet_damage(attack: &SkillDamage, owner: &Character, target: &Target) -> f32 {
+    get_skill_damage_with_multiplier(attack, owner, target, get_damage_done(owner).get_pet_multiplier(&attack.size))
+}
+
+fn get_skill_damage_with_multiplier(skill: &SkillDamage, caster: &Character, target: &Target, multiplier: f32) -> f32 {
+    let health = target.get_health_fraction();
+    let bloodthirsty = caster.get_power_against(health) - caster.get_power();
+    let damage = (get_skill_tooltip(skill, caster) + skill.power_coefficient * bloodthirsty as f32) * multiplier;
+    match &skill.execute {
+        Some(execute) if health < execute.threshold => damage * (1.0 + execute.bonus),
+        _ => damage,
+    }
+}
+
+/// Damage of a light or heavy attack against `target`, `coefficient * (power + max resource / 10.5)` with single target damage done.
+pub fn get_attack_damage(attack: &Attack, caster: &Character, target: &Target) -> f32 {
+    let power = caster.get_power_against(target.get_health_fraction()) as f32;
+    let max_resource = caster.get_max_resource(&attack.resource) as f32;
+    attack.coefficient * (power + max_resource / 10.5) * get_damage_done(caster).get_multiplier(&DamageSize::SINGLETARGET)
+}
@@ -0,0 +1,26 @@
+use crate::data::skill_damage::SkillDamage;
+use crate::engine::world::event::UnitId;
+
+/// A damage over time or ground effect from a skill on a target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveDamageOverTime {
+    pub source: UnitId,
+    pub target: UnitId,
+    pub skill: &'static SkillDamage,
+    /// Tooltip of each tick at the cast, for skills which snapshot the caster's stats.
+    pub snapshot: Option<f32>,
+    pub next_tick_ms: u64,
+    pub expires_at_ms: u64,
+}
+
+impl ActiveDamageOverTime {
+    /// Whether another tick is due by `time_ms`. Damage over time ticks for the last time as it expires, ground effects tick on placement and stop before they expire.
+    pub fn is_due(&self, time_ms: u64) -> bool {
+        let in_duration = if self.skill.ground {self.next_tick_ms < self.expires_at_ms} else {self.next_tick_ms <= self.expires_at_ms};
+        in_duration && self.next_tick_ms <= time_ms
+    }
+
+    pub fn has_expired(&self, time_ms: u64) -> bool {
+        self.expires_at_ms <= time_ms
+    }
+}
@@ -1,6 +1,28 @@
 use std::collections::HashMap;
 
+use crate::data::attack::{Attack, GLOBAL_COOLDOWN_MS};
+use crate::data::cast::CAST_INFO_BY_ID;
+use crate::data::enchant::{WEAPON_ENCHANT_DURATION_MS, WeaponEnchantEffect};
+use crate::data::pet::{PET_BY_ID, PetData};
+use crate::data::poison::PoisonEffect;
+use crate::data::skill_timing::SKILL_TIMING_BY_ID;
+use crate::data::skill_damage::{AOE_TARGET_CAP, Recast, SKILL_DAMAGE_BY_ID, SkillDamage};
+use crate::data::ultimate::{ULTIMATE_GENERATION_INTERVAL_MS, ULTIMATE_PER_HEAVY_ATTACK, ULTIMATE_PER_LIGHT_ATTACK};
+use crate::data::status_effect::{STATUS_EFFECT_CHANCE_DEFAULT, STATUS_EFFECT_CHANCE_ENCHANT, StatusEffect, get_status_effect};
 use crate::engine::{ID, STACKS, player::{character::Character, sets::SET_REGISTRY_MAP}};
+use crate::engine::world::cast::ActiveCast;
+use crate::engine::world::damage::{DamageRecord, PhaseDps, get_attack_damage, get_pet_damage, get_skill_damage, mitigate};
+use crate::engine::world::damage_over_time::ActiveDamageOverTime;
+use crate::engine::world::heal::{HealOverTime, HealRecord};
+use crate::engine::world::pet::{PET_UNIT_ID_START, Pet};
+use crate::engine::world::status_effect::ActiveStatusEffect;
+use crate::engine::player::mitigation::IncomingHit;
+use crate::engine::player::healing::{Heal, HealResult, calculate_heal};
+use crate::models::resource::PlayerAttributeType;
+use crate::engine::world::target::{Debuff, Target};
+use crate::engine::world::weave::WeaveTiming;
+use crate::engine::warning::Warning;
+use crate::models::damage::{DamageSize, DamageTime, DamageType};
 
 pub type UnitId = ID;
 
@@ -8,8 +30,34 @@ pub trait World {
     fn character(&self, id: UnitId) -> &Character;
     fn character_mut(&mut self, id: UnitId) -> &mut Character;
 
-    fn add_buff(&mut self, target: UnitId, buff: ID, stacks: STACKS);
-    fn remove_buff(&mut self, target: UnitId, buff: ID);
+    fn add_buff_from_source(&mut self, source: UnitId, target: UnitId, buff: ID, stacks: STACKS);
+    fn remove_buff_from_source(&mut self, source: UnitId, target: UnitId, buff: ID);
+
+    /// Adds a buff the target applies to itself.
+    fn add_buff(&mut self, target: UnitId, buff: ID, stacks: STACKS) {
+        self.add_buff_from_source(target, target, buff, stacks);
+    }
+
+    fn remove_buff(&mut self, target: UnitId, buff: ID) {
+        self.remove_buff_from_source(target, target, buff);
+    }
+
+    /// Adds a buff granted by a set the target is wearing. The set is the source, so the target's own timed casts of the buff never remove it.
+    fn add_set_buff(&mut self, target: UnitId, set_id: u16, buff: ID, stacks: STACKS) {
+        self.add_buff_from_source(get_set_source(set_id), target, buff, stacks);
+    }
+
+    fn remove_set_buff(&mut self, target: UnitId, set_id: u16, buff: ID) {
+        self.remove_buff_from_source(get_set_source(set_id), target, buff);
+    }
+}
+
+/// Source ids of set buffs start here so they never collide with players, targets or pets.
+pub const SET_SOURCE_ID_START: UnitId = 2_000_000;
+
+/// Source of the buffs granted by `set_id`.
+pub fn get_set_source(set_id: u16) -> UnitId {
+    SET_SOURCE_ID_START + set_id as UnitId
 }
 
 
@@ -23,6 +71,17 @@ pub enum Event {
         skill_id: u32,
     },
 
+    LightAttack {
+        caster: UnitId,
+        target: UnitId,
+    },
+
+    HeavyAttack {
+        caster: UnitId,
+        target: UnitId,
+    },
+
+    /// A `source` of `None` means the target applied the buff to itself.
     BuffGained {
         source: Option<UnitId>,
         target: UnitId,
@@ -31,6 +90,7 @@ pub enum Event {
     },
 
     BuffFaded {
+        source: Option<UnitId>,
         target: UnitId,
         buff_id: ID,
     },
@@ -44,6 +104,23 @@ pub enum Event {
     BarSwapped {
         player: UnitId,
     },
+    BlockStarted {
+        player: UnitId,
+    },
+    BlockStopped {
+        player: UnitId,
+    },
+    PotionUsed {
+        player: UnitId,
+        potion_id: ID,
+    },
+
+    /// A status effect was applied to a target or player, either from a damage proc or directly.
+    StatusEffectApplied {
+        source: UnitId,
+        target: UnitId,
+        effect: StatusEffect,
+    },
 }
 
 pub struct SetDescriptor {
@@ -65,13 +142,35 @@ pub trait SetInstance: Send {
     );
 }
 
+#[derive(Default)]
 pub struct SetManager {
     active_sets: HashMap<UnitId, HashMap<u16, Box<dyn SetInstance>>>,
 }
 
+#[derive(Default)]
 pub struct GameState {
     players: HashMap<UnitId, Character>,
+    targets: HashMap<UnitId, Target>,
     set_manager: SetManager,
+    time_ms: u64,
+    buff_expiries: HashMap<(UnitId, UnitId, ID), u64>,
+    damage_log: Vec<DamageRecord>,
+    heals_over_time: Vec<HealOverTime>,
+    heal_log: Vec<HealRecord>,
+    /// Accumulated status effect chance of each source against each unit. The effect applies each time this reaches 1.
+    status_effect_meters: HashMap<(UnitId, UnitId, StatusEffect), f32>,
+    status_effects: Vec<ActiveStatusEffect>,
+    damages_over_time: Vec<ActiveDamageOverTime>,
+    pets: Vec<Pet>,
+    /// Owner of every pet summoned so far, including pets which have since expired.
+    pet_owners: HashMap<UnitId, UnitId>,
+    casts: HashMap<UnitId, ActiveCast>,
+    /// Time at which each caster's global cooldown is over.
+    global_cooldowns: HashMap<UnitId, u64>,
+    /// Time at which each caster can use each skill on cooldown again.
+    cooldowns: HashMap<(UnitId, ID), u64>,
+    /// Time at which the buffs of each caster's last cast of each skill run out.
+    skill_expiries: HashMap<(UnitId, ID), u64>,
 }
 
 impl World for GameState {
@@ -83,41 +182,554 @@ impl World for GameState {
         self.players.get_mut(&id).expect("invalid UnitId")
     }
 
-    fn add_buff(&mut self, target: UnitId, buff_id: ID, stacks: STACKS) {
+    fn add_buff_from_source(&mut self, source: UnitId, target: UnitId, buff_id: ID, stacks: STACKS) {
         let c = self.character_mut(target);
-        c.add_buff(buff_id, stacks);
+        c.add_buff_from_source(buff_id, source, stacks);
         c.recompute_buff_supplemental_state();
     }
 
-    fn remove_buff(&mut self, target: UnitId, buff_id: ID) {
+    fn remove_buff_from_source(&mut self, source: UnitId, target: UnitId, buff_id: ID) {
         let c = self.character_mut(target);
-        c.remove_buff(buff_id);
+        c.remove_buff_from_source(buff_id, source);
         c.recompute_buff_supplemental_state();
     }
 }
 
 
 impl GameState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_player(&mut self, character: Character) {
+        let id = character.id();
+        self.players.insert(id, character);
+        self.evaluate_sets_for_player(id);
+        self.character_mut(id).recompute_all_supplemental_state();
+    }
+
+    pub fn add_target(&mut self, target: Target) {
+        self.targets.insert(target.id(), target);
+    }
+
+    pub fn target(&self, id: UnitId) -> &Target {
+        &self.targets[&id]
+    }
+
+    pub fn target_mut(&mut self, id: UnitId) -> &mut Target {
+        self.targets.get_mut(&id).expect("invalid UnitId")
+    }
+
+    /// Every enemy in the encounter, in order of id.
+    pub fn get_target_ids(&self) -> Vec<UnitId> {
+        let mut ids: Vec<UnitId> = self.targets.keys().copied().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Enemies hit by a skill cast at `target`. Area of effect skills also hit the other enemies, up to the target cap.
+    fn get_targets_hit(&self, target: UnitId, size: DamageSize) -> Vec<UnitId> {
+        let mut hit = vec![target];
+        if size == DamageSize::MULTITARGET {
+            hit.extend(self.get_target_ids().into_iter().filter(|id| *id != target).take(AOE_TARGET_CAP - 1));
+        }
+        hit
+    }
+
+    pub fn time_ms(&self) -> u64 {
+        self.time_ms
+    }
+
+    pub fn get_damage_log(&self) -> &Vec<DamageRecord> {
+        &self.damage_log
+    }
+
+    pub fn get_total_damage(&self, source: UnitId) -> f32 {
+        self.damage_log.iter().filter(|record| record.source == source).map(|record| record.amount).sum()
+    }
+
+    /// Damage per second of `source` to `target` before and after the first hit on `target` in the execute phase.
+    pub fn get_dps_by_phase(&self, source: UnitId, target: UnitId) -> PhaseDps {
+        let on_target = || self.damage_log.iter().filter(move |record| record.target == target);
+        let execute_ms = on_target().filter(|record| record.execute).map(|record| record.time_ms).min().unwrap_or(self.time_ms);
+        let (mut pre_execute, mut execute) = (0.0, 0.0);
+        for record in on_target().filter(|record| record.source == source) {
+            if record.execute {execute += record.amount} else {pre_execute += record.amount}
+        }
+        let per_second = |damage: f32, duration_ms: u64| if duration_ms == 0 {0.0} else {damage * 1000.0 / duration_ms as f32};
+        PhaseDps {
+            pre_execute: per_second(pre_execute, execute_ms),
+            execute: per_second(execute, self.time_ms - execute_ms),
+        }
+    }
+
+    pub fn get_total_damage_to(&self, target: UnitId) -> f32 {
+        self.damage_log.iter().filter(|record| record.target == target).map(|record| record.amount).sum()
+    }
+
+    pub fn get_heal_log(&self) -> &Vec<HealRecord> {
+        &self.heal_log
+    }
+
+    /// Health restored by `source`, not counting overheal.
+    pub fn get_total_healing(&self, source: UnitId) -> f32 {
+        self.heal_log.iter().filter(|record| record.source == source).map(|record| record.amount).sum()
+    }
+
+    /// Adds a buff to a player which is removed after `duration_ms`. Reapplying refreshes the duration.
+    pub fn add_timed_buff(&mut self, target: UnitId, buff_id: ID, stacks: STACKS, duration_ms: u64) {
+        self.add_timed_buff_from_source(target, target, buff_id, stacks, duration_ms);
+    }
+
+    /// Like `add_timed_buff`, but each source's instance of the buff expires independently.
+    pub fn add_timed_buff_from_source(&mut self, source: UnitId, target: UnitId, buff_id: ID, stacks: STACKS, duration_ms: u64) {
+        self.add_buff_from_source(source, target, buff_id, stacks);
+        self.buff_expiries.insert((source, target, buff_id), self.time_ms + duration_ms);
+    }
+
+    /// Gives a player a damage shield which is removed after `duration_ms`, returning its strength.
+    pub fn add_shield(&mut self, target: UnitId, shield_id: ID, amount: f32, duration_ms: u64) -> f32 {
+        let expires_at_ms = self.time_ms + duration_ms;
+        self.character_mut(target).add_shield(shield_id, amount, Some(expires_at_ms))
+    }
+
+    fn advance_time(&mut self, delta_ms: u64) {
+        self.time_ms += delta_ms;
+        let time_ms = self.time_ms;
+
+        for target in self.targets.values_mut() {
+            target.expire_debuffs(time_ms);
+        }
+        let intervals = time_ms / ULTIMATE_GENERATION_INTERVAL_MS - (time_ms - delta_ms) / ULTIMATE_GENERATION_INTERVAL_MS;
+        for player in self.players.values_mut() {
+            player.expire_shields(time_ms);
+            let generation = player.get_ultimate_generation();
+            for _ in 0..intervals {
+                player.gain_ultimate(generation);
+            }
+        }
+        self.tick_casts();
+        self.tick_heals_over_time();
+        self.tick_status_effects();
+        self.tick_damages_over_time();
+        self.tick_pets();
+
+        let expired: Vec<(UnitId, UnitId, ID)> = self.buff_expiries
+            .iter()
+            .filter(|(_, expires_at)| **expires_at <= time_ms)
+            .map(|(key, _)| *key)
+            .collect();
+        for (source, target, buff_id) in expired {
+            self.buff_expiries.remove(&(source, target, buff_id));
+            self.remove_buff_from_source(source, target, buff_id);
+        }
+    }
+
+    /// Deals damage to a target, which may apply the status effect of `damage_type`.
+    pub fn deal_damage(&mut self, source: UnitId, target: UnitId, ability_id: ID, damage_type: DamageType, amount: f32) {
+        self.deal_damage_at(source, target, ability_id, damage_type, amount, self.time_ms);
+    }
+
+    fn deal_damage_at(&mut self, source: UnitId, target: UnitId, ability_id: ID, damage_type: DamageType, amount: f32, time_ms: u64) {
+        if self.record_damage(source, target, ability_id, damage_type, amount, time_ms) {
+            self.roll_status_effect(source, target, &damage_type, STATUS_EFFECT_CHANCE_DEFAULT);
+        }
+    }
+
+    /// Mitigates and logs a hit. Returns false without logging anything if the target is already dead.
+    fn record_damage(&mut self, source: UnitId, target: UnitId, ability_id: ID, damage_type: DamageType, amount: f32, time_ms: u64) -> bool {
+        if self.target(target).is_dead() {return false}
+        let armour = self.target(target).get_armour();
+        let penetration = self.character(self.get_owner(source)).get_penetration();
+        let amount = mitigate(amount * self.target(target).get_damage_taken_multiplier(), &damage_type, armour, penetration);
+        let execute = self.target(target).is_in_execute();
+        self.target_mut(target).take_damage(amount);
+        self.damage_log.push(DamageRecord {
+            time_ms,
+            source,
+            target,
+            ability_id,
+            damage_type,
+            amount,
+            execute,
+        });
+        true
+    }
+
+    /// Adds `chance`, increased by the source's Charged traits, to the status effect meter of `damage_type`, and applies the status effect if the meter fills.
+    /// Procs are deterministic so that over many hits the proc rate matches the chance.
+    fn roll_status_effect(&mut self, source: UnitId, target: UnitId, damage_type: &DamageType, chance: f32) {
+        let Some(data) = get_status_effect(damage_type) else {return};
+        let modifier = self.players.get(&self.get_owner(source)).map_or(0.0, |character| character.get_utility().get_status_effect_chance());
+        let meter = self.status_effect_meters.entry((source, target, data.effect)).or_insert(0.0);
+        *meter += chance * (1.0 + modifier);
+        if *meter >= 1.0 {
+            *meter -= 1.0;
+            self.apply_status_effect(source, target, data.effect);
+            self.emit_event_to_sets(&Event::StatusEffectApplied { source, target, effect: data.effect });
+        }
+    }
+
+    /// Applies a status effect and its secondary debuffs to a target or player.
+    pub fn apply_status_effect(&mut self, source: UnitId, target: UnitId, effect: StatusEffect) {
+        let data = effect.get_data();
+        let expires_at_ms = self.time_ms + data.duration_ms;
+        if self.targets.contains_key(&target) {
+            for id in std::iter::once(&data.id).chain(data.debuffs) {
+                self.target_mut(target).add_debuff(*id, Debuff {
+                    stacks: 1,
+                    value: None,
+                    expires_at_ms: Some(expires_at_ms),
+                });
+            }
+        } else {
+            for id in std::iter::once(&data.id).chain(data.debuffs) {
+                self.add_timed_buff_from_source(source, target, *id, 1, data.duration_ms);
+            }
+        }
+        self.status_effects.retain(|active| !(active.target == target && active.effect == effect));
+        self.status_effects.push(ActiveStatusEffect {
+            source,
+            target,
+            effect,
+            next_tick_ms: self.time_ms + data.tick_interval_ms,
+            expires_at_ms,
+        });
+    }
+
+    pub fn get_status_effects(&self, target: UnitId) -> Vec<StatusEffect> {
+        self.status_effects.iter().filter(|active| active.target == target).map(|active| active.effect).collect()
+    }
+
+    fn tick_status_effects(&mut self) {
+        let time_ms = self.time_ms;
+        let mut status_effects = std::mem::take(&mut self.status_effects);
+        for active in status_effects.iter_mut() {
+            let data = active.effect.get_data();
+            while active.is_due(time_ms) {
+                let damage = data.tick_damage.unwrap_or(0.0);
+                if self.targets.contains_key(&active.target) {
+                    self.record_damage(active.source, active.target, data.id, data.damage_type, damage, active.next_tick_ms);
+                } else {
+                    let penetration = self.players.get(&active.source).map_or(0, |character| character.get_penetration());
+                    self.character_mut(active.target).take_hit(&IncomingHit::new(damage, data.damage_type).with_penetration(penetration));
+                }
+                active.next_tick_ms += data.tick_interval_ms.max(1);
+            }
+        }
+        status_effects.retain(|active| !active.has_expired(time_ms));
+        self.status_effects = status_effects;
+    }
+
+    /// Puts a skill's damage over time or ground effect on a target. Recasting a skill which snapshots also updates the snapshot.
+    pub fn add_damage_over_time(&mut self, source: UnitId, target: UnitId, skill: &'static SkillDamage) {
+        let time_ms = self.time_ms;
+        let snapshot = skill.snapshot.then(|| get_skill_damage(skill, self.character(source), self.target(target)));
+        let expires_at_ms = time_ms + skill.duration_ms;
+        let existing = self.damages_over_time.iter().position(|dot| dot.source == source && dot.target == target && dot.skill.id == skill.id);
+        if let Some(index) = existing {
+            match skill.recast {
+                Recast::Overwrite => {self.damages_over_time.remove(index);},
+                Recast::Refresh => {
+                    let dot = &mut self.damages_over_time[index];
+                    dot.snapshot = snapshot;
+                    dot.expires_at_ms = expires_at_ms;
+                    return;
+                },
+            }
+        }
+        self.damages_over_time.push(ActiveDamageOverTime {
+            source,
+            target,
+            skill,
+            snapshot,
+            next_tick_ms: if skill.ground {time_ms} else {time_ms + skill.frequency_ms},
+            expires_at_ms,
+        });
+        self.tick_damages_over_time();
+    }
+
+    pub fn get_damages_over_time(&self, target: UnitId) -> Vec<&ActiveDamageOverTime> {
+        self.damages_over_time.iter().filter(|dot| dot.target == target).collect()
+    }
+
+    fn tick_damages_over_time(&mut self) {
+        let time_ms = self.time_ms;
+        let mut damages_over_time = std::mem::take(&mut self.damages_over_time);
+        for dot in damages_over_time.iter_mut() {
+            while dot.is_due(time_ms) {
+                let damage = dot.snapshot.unwrap_or_else(|| get_skill_damage(dot.skill, self.character(dot.source), self.target(dot.target)));
+                self.deal_damage_at(dot.source, dot.target, dot.skill.id, dot.skill.damage_type, damage, dot.next_tick_ms);
+                dot.next_tick_ms += dot.skill.frequency_ms.max(1);
+            }
+        }
+        damages_over_time.retain(|dot| !dot.has_expired(time_ms));
+        self.damages_over_time = damages_over_time;
+    }
+
+    pub fn get_cast(&self, caster: UnitId) -> Option<&ActiveCast> {
+        self.casts.get(&caster)
+    }
+
+    pub fn is_casting(&self, caster: UnitId) -> bool {
+        self.casts.contains_key(&caster)
+    }
+
+    /// Time left until `caster` can use another skill. Casts and channels longer than the global cooldown hold it until they end.
+    pub fn get_global_cooldown_remaining_ms(&self, caster: UnitId) -> u64 {
+        self.global_cooldowns.get(&caster).map_or(0, |ready_at| ready_at.saturating_sub(self.time_ms))
+    }
+
+    pub fn get_cooldown_remaining_ms(&self, caster: UnitId, skill_id: ID) -> u64 {
+        self.cooldowns.get(&(caster, skill_id)).map_or(0, |ready_at| ready_at.saturating_sub(self.time_ms))
+    }
+
+    /// Time left on what the last cast of `skill_id` by `caster` applied, either its damage over time on `target` or its buffs.
+    pub fn get_skill_remaining_ms(&self, caster: UnitId, target: UnitId, skill_id: ID) -> u64 {
+        let expires_at_ms = self.damages_over_time
+            .iter()
+            .find(|dot| dot.source == caster && dot.target == target && dot.skill.id == skill_id)
+            .map(|dot| dot.expires_at_ms)
+            .or_else(|| self.skill_expiries.get(&(caster, skill_id)).copied());
+        expires_at_ms.map_or(0, |expires_at_ms| expires_at_ms.saturating_sub(self.time_ms))
+    }
+
+    /// Whether a rotation should recast `skill_id`: it is off cooldown, and what it applied has run out or runs out within `within_ms`.
+    pub fn should_recast(&self, caster: UnitId, target: UnitId, skill_id: ID, within_ms: u64) -> bool {
+        self.get_cooldown_remaining_ms(caster, skill_id) == 0 && self.get_skill_remaining_ms(caster, target, skill_id) <= within_ms
+    }
+
+    /// Stops the cast or channel of `caster` without any hits left in it. The global cooldown is only held for its usual length.
+    pub fn interrupt_cast(&mut self, caster: UnitId) {
+        if let Some(cast) = self.casts.remove(&caster) {
+            let ready_at = cast.started_at_ms + GLOBAL_COOLDOWN_MS;
+            self.global_cooldowns.insert(caster, ready_at);
+        }
+    }
+
+    fn tick_casts(&mut self) {
+        let time_ms = self.time_ms;
+        let mut hits = Vec::new();
+        for cast in self.casts.values_mut() {
+            while cast.is_due(time_ms) {
+                hits.push((cast.next_tick_ms, cast.caster, cast.target, cast.info.id));
+                cast.tick();
+            }
+        }
+        self.casts.retain(|_, cast| !cast.has_ended(time_ms));
+        hits.sort();
+        for (tick_ms, caster, target, skill_id) in hits {
+            if self.targets.contains_key(&target) {
+                self.resolve_skill_damage(caster, target, skill_id, tick_ms);
+            }
+        }
+    }
+
+    /// Summons a pet for `owner` which attacks `target`, returning its unit id. Resummoning a pet replaces it.
+    pub fn summon_pet(&mut self, owner: UnitId, target: UnitId, data: &'static PetData) -> UnitId {
+        self.pets.retain(|pet| !(pet.owner == owner && pet.data.id == data.id));
+        let id = PET_UNIT_ID_START + self.pet_owners.len() as UnitId;
+        self.pet_owners.insert(id, owner);
+        self.pets.push(Pet {
+            id,
+            owner,
+            data,
+            target,
+            next_attack_ms: self.time_ms + data.attack.frequency_ms,
+            expires_at_ms: data.duration_ms.map(|duration_ms| self.time_ms + duration_ms),
+        });
+        id
+    }
+
+    pub fn dismiss_pet(&mut self, pet: UnitId) {
+        self.pets.retain(|active| active.id != pet);
+    }
+
+    pub fn get_pets(&self, owner: UnitId) -> Vec<&Pet> {
+        self.pets.iter().filter(|pet| pet.owner == owner).collect()
+    }
+
+    /// The player who owns `unit` if it is a pet, otherwise `unit` itself.
+    pub fn get_owner(&self, unit: UnitId) -> UnitId {
+        self.pet_owners.get(&unit).copied().unwrap_or(unit)
+    }
+
+    /// Damage done by every pet `owner` has summoned.
+    pub fn get_total_pet_damage(&self, owner: UnitId) -> f32 {
+        self.damage_log.iter()
+            .filter(|record| self.pet_owners.get(&record.source) == Some(&owner))
+            .map(|record| record.amount)
+            .sum()
+    }
+
+    fn tick_pets(&mut self) {
+        let time_ms = self.time_ms;
+        let mut attacks = Vec::new();
+        for pet in self.pets.iter_mut() {
+            while pet.is_due(time_ms) {
+                attacks.push((pet.id, pet.owner, pet.target, pet.data.attack, pet.next_attack_ms));
+                pet.next_attack_ms += pet.data.attack.frequency_ms.max(1);
+            }
+        }
+        for (pet, owner, target, attack, attack_ms) in attacks {
+            if !self.targets.contains_key(&target) {continue}
+            let owner = self.character(owner);
+            let damage = get_pet_damage(attack, owner, self.target(target));
+            self.deal_damage_at(pet, target, attack.id, attack.damage_type, damage, attack_ms);
+        }
+        self.pets.retain(|pet| !pet.has_expired(time_ms));
+    }
+
+    /// Heals a player, restoring health up to their maximum.
+    pub fn heal(&mut self, source: UnitId, target: UnitId, heal: &Heal) -> HealResult {
+        self.heal_at(source, target, heal, self.time_ms)
+    }
+
+    fn heal_at(&mut self, source: UnitId, target: UnitId, heal: &Heal, time_ms: u64) -> HealResult {
+        let result = calculate_heal(self.character(source), self.character(target), heal);
+        self.character_mut(target).restore_resource(&PlayerAttributeType::Health, result.effective as u32);
+        self.heal_log.push(HealRecord {
+            time_ms,
+            source,
+            target,
+            ability_id: heal.ability_id,
+            amount: result.effective,
+            overheal: result.overheal,
+        });
+        result
+    }
+
+    /// Heals `target` every `interval_ms` for `duration_ms`, starting one interval from now. Reapplying the same heal from the same source replaces it.
+    pub fn add_heal_over_time(&mut self, source: UnitId, target: UnitId, heal: Heal, interval_ms: u64, duration_ms: u64) {
+        self.heals_over_time.retain(|hot| !(hot.source == source && hot.target == target && hot.heal.ability_id == heal.ability_id));
+        self.heals_over_time.push(HealOverTime {
+            source,
+            target,
+            heal,
+            interval_ms,
+            next_tick_ms: self.time_ms + interval_ms,
+            expires_at_ms: self.time_ms + duration_ms,
+        });
+    }
+
+    fn tick_heals_over_time(&mut self) {
+        let time_ms = self.time_ms;
+        let mut heals_over_time = std::mem::take(&mut self.heals_over_time);
+        for hot in heals_over_time.iter_mut() {
+            while hot.is_due(time_ms) {
+                self.heal_at(hot.source, hot.target, &hot.heal, hot.next_tick_ms);
+                hot.next_tick_ms += hot.interval_ms.max(1);
+            }
+        }
+        heals_over_time.retain(|hot| !hot.has_expired(time_ms));
+        self.heals_over_time = heals_over_time;
+    }
+
+    fn proc_weapon_enchants(&mut self, caster: UnitId, target: UnitId) {
+        let time_ms = self.time_ms;
+        for enchant_proc in self.character_mut(caster).proc_weapon_enchants(time_ms) {
+            let enchant = enchant_proc.enchant;
+            match &enchant.effect {
+                WeaponEnchantEffect::Damage(damage_type) => {
+                    self.record_damage(caster, target, enchant.id, *damage_type, enchant_proc.value, time_ms);
+                    self.roll_status_effect(caster, target, damage_type, STATUS_EFFECT_CHANCE_ENCHANT);
+                },
+                WeaponEnchantEffect::Absorb(resource) => {
+                    self.deal_damage(caster, target, enchant.id, DamageType::MAGIC, enchant_proc.value);
+                    self.character_mut(caster).restore_resource(resource, enchant_proc.value as u32);
+                },
+                WeaponEnchantEffect::ReduceArmour | WeaponEnchantEffect::ReducePower => {
+                    self.target_mut(target).add_debuff(enchant.id, Debuff {
+                        stacks: 1,
+                        value: Some(-enchant_proc.value as f64),
+                        expires_at_ms: Some(time_ms + WEAPON_ENCHANT_DURATION_MS),
+                    });
+                },
+                WeaponEnchantEffect::IncreasePower => {
+                    self.character_mut(caster).set_buff_value(enchant.id, enchant_proc.value as u32);
+                    self.add_timed_buff(caster, enchant.id, 1, WEAPON_ENCHANT_DURATION_MS);
+                },
+                WeaponEnchantEffect::DamageShield => {
+                    self.add_shield(caster, enchant.id, enchant_proc.value, WEAPON_ENCHANT_DURATION_MS);
+                },
+            }
+        }
+    }
+
+    fn proc_poison(&mut self, caster: UnitId, target: UnitId) {
+        let time_ms = self.time_ms;
+        let Some(poison) = self.character_mut(caster).proc_poison(time_ms) else {return};
+        match poison.effect {
+            PoisonEffect::Damage => self.deal_damage(caster, target, poison.id, poison.damage_type, poison.damage),
+            PoisonEffect::Drain(resource) => {
+                self.deal_damage(caster, target, poison.id, poison.damage_type, poison.damage);
+                self.character_mut(caster).restore_resource(&resource, poison.damage as u32);
+            },
+            PoisonEffect::Debuff(debuff_id) => {
+                self.target_mut(target).add_debuff(debuff_id, Debuff {
+                    stacks: 1,
+                    value: None,
+                    expires_at_ms: Some(time_ms + poison.duration_ms),
+                });
+            },
+        }
+    }
+
+    fn use_potion(&mut self, player: UnitId, potion_id: ID) {
+        let time_ms = self.time_ms;
+        if let Some(potion_use) = self.character_mut(player).use_potion(potion_id, time_ms) {
+            for buff_id in potion_use.potion.buffs {
+                self.add_timed_buff(player, *buff_id, 1, potion_use.duration_ms);
+            }
+            let (health, magicka, stamina) = potion_use.restore;
+            let character = self.character_mut(player);
+            character.restore_resource(&PlayerAttributeType::Health, health);
+            character.restore_resource(&PlayerAttributeType::Magicka, magicka);
+            character.restore_resource(&PlayerAttributeType::Stamina, stamina);
+            let ultimate = self.character(player).get_ultimate_on_potion();
+            self.character_mut(player).gain_ultimate(ultimate);
+        }
+    }
+
     pub fn evaluate_sets_for_player(&mut self, player: UnitId) {
-        let active_sets = self
+        let mut active_sets = self
             .set_manager
             .active_sets
-            .entry(player)
-            .or_default();
+            .remove(&player)
+            .unwrap_or_default();
 
-        for (set_id, reference) in &SET_REGISTRY_MAP {
-            let pieces = self.character(player).get_set_piece_count(&reference.id);
+        let character = self.character(player);
+        let bar = character.get_active_bar();
+        let on_bar: Vec<(&SetDescriptor, bool)> = SET_REGISTRY_MAP.values()
+            .map(|reference| (*reference, character.is_set_active_on_bar(&reference.id, bar)))
+            .collect();
+
+        for (reference, on_bar) in on_bar {
             let active = active_sets.contains_key(&reference.id);
 
-            if pieces >= reference.min_pieces && !active {
+            if on_bar && !active {
                 let mut inst = (reference.instance_factory)();
                 inst.on_activate(player, self);
                 active_sets.insert(reference.id, inst);
-            } else if pieces < reference.min_pieces && active {
+            } else if !on_bar && active {
                 let mut inst = active_sets.remove(&reference.id).unwrap();
                 inst.on_deactivate(player, self);
             }
         }
+
+        self.set_manager.active_sets.insert(player, active_sets);
+    }
+
+    fn emit_event_to_sets(&mut self, event: &Event) {
+        let mut active_sets = std::mem::take(&mut self.set_manager.active_sets);
+
+        for (owner, sets) in active_sets.iter_mut() {
+            for inst in sets.values_mut() {
+                inst.on_event(*owner, event, self);
+            }
+        }
+
+        self.set_manager.active_sets = active_sets;
     }
 
     pub fn handle_event(&mut self, event: Event) {
@@ -126,29 +738,910 @@ impl GameState {
                 self.resolve_skill(caster, target, skill_id);
             }
 
+            Event::LightAttack { caster, target } => {
+                self.character_mut(caster).gain_ultimate(ULTIMATE_PER_LIGHT_ATTACK);
+                let attack = self.character(caster).get_light_attack();
+                self.resolve_attack(caster, target, attack);
+            }
+
+            Event::HeavyAttack { caster, target } => {
+                self.character_mut(caster).gain_ultimate(ULTIMATE_PER_HEAVY_ATTACK);
+                let attack = self.character(caster).get_heavy_attack();
+                self.resolve_attack(caster, target, attack);
+            }
+
+            Event::BarSwapped { player } => {
+                if self.character_mut(player).swap_bars(None) {
+                    self.interrupt_cast(player);
+                    self.evaluate_sets_for_player(player);
+                    self.character_mut(player).recompute_all_supplemental_state();
+                }
+            }
+
             Event::EquipChanged { player }
-            | Event::PlayerUpdated { player }
-            | Event::BarSwapped { player } => {
+            | Event::PlayerUpdated { player } => {
                 self.evaluate_sets_for_player(player);
                 self.character_mut(player).recompute_all_supplemental_state();
             }
 
-            Event::BuffGained { target, buff_id, stacks, .. } => {
-                self.add_buff(target, buff_id, stacks);
+            Event::BuffGained { source, target, buff_id, stacks } => {
+                self.add_buff_from_source(source.unwrap_or(target), target, buff_id, stacks);
+            }
+
+            Event::BuffFaded { source, target, buff_id } => {
+                self.remove_buff_from_source(source.unwrap_or(target), target, buff_id);
             }
 
-            Event::BuffFaded { target, buff_id } => {
-                self.remove_buff(target, buff_id);
+            Event::BlockStarted { player } => {
+                self.character_mut(player).set_blocking(true);
             }
 
-            Event::Tick { .. } => {}
+            Event::BlockStopped { player } => {
+                self.character_mut(player).set_blocking(false);
+            }
+
+            Event::PotionUsed { player, potion_id } => {
+                self.use_potion(player, potion_id);
+            }
+
+            Event::Tick { delta_ms } => {
+                self.advance_time(delta_ms);
+            }
+
+            Event::StatusEffectApplied { source, target, effect } => {
+                self.apply_status_effect(source, target, effect);
+            }
         }
 
         self.emit_event_to_sets(&event);
     }
 
+    /// Lands a light or heavy attack, which restores resources and procs weapon enchants and poisons like a skill.
+    fn resolve_attack(&mut self, caster: UnitId, target: UnitId, attack: &'static Attack) {
+        self.character_mut(caster).restore_resource(&attack.resource, attack.restore);
+        if self.targets.contains_key(&target) {
+            let damage = get_attack_damage(attack, self.character(caster), self.target(target));
+            self.deal_damage(caster, target, attack.id, attack.damage_type, damage);
+            self.proc_weapon_enchants(caster, target);
+            self.proc_poison(caster, target);
+        }
+    }
+
+    /// Channels a fully charged heavy attack, advancing time until it lands.
+    pub fn channel_heavy_attack(&mut self, caster: UnitId, target: UnitId) {
+        let channel_ms = self.character(caster).get_heavy_attack().channel_ms;
+        self.handle_event(Event::Tick { delta_ms: channel_ms });
+        self.handle_event(Event::HeavyAttack { caster, target });
+    }
+
+    /// Light attacks, casts `skill_id` once the light attack lands and waits out the global cooldown for the next weave.
+    pub fn weave(&mut self, caster: UnitId, target: UnitId, skill_id: u32, timing: &WeaveTiming) {
+        self.handle_event(Event::LightAttack { caster, target });
+        self.handle_event(Event::Tick { delta_ms: timing.light_attack_ms });
+        self.handle_event(Event::SkillUsed { caster, target, skill_id });
+        let delta_ms = self.get_global_cooldown_remaining_ms(caster) + timing.latency_ms;
+        self.handle_event(Event::Tick { delta_ms });
+    }
+
     fn resolve_skill(&mut self, caster: UnitId, target: UnitId, skill_id: u32) {
-        //
+        if let Some(cast) = self.casts.get(&caster) {
+            let casting = cast.info.id;
+            self.character_mut(caster).add_warning(Warning::SkillUsedWhileCasting { skill_id, casting });
+            return;
+        }
+        let remaining_ms = self.get_global_cooldown_remaining_ms(caster);
+        if remaining_ms > 0 {
+            self.character_mut(caster).add_warning(Warning::GlobalCooldown { skill_id, remaining_ms });
+            return;
+        }
+        let remaining_ms = self.get_cooldown_remaining_ms(caster, skill_id);
+        if remaining_ms > 0 {
+            self.character_mut(caster).add_warning(Warning::SkillOnCooldown { skill_id, remaining_ms });
+            return;
+        }
+        if let Some(cost) = self.character(caster).get_ultimate_cost(&skill_id) {
+            let ultimate = self.character(caster).get_ultimate();
+            if !self.character_mut(caster).spend_ultimate(&skill_id) {
+                self.character_mut(caster).add_warning(Warning::NotEnoughUltimate { skill_id, cost, ultimate });
+                return;
+            }
+        }
+        let cast_ms = CAST_INFO_BY_ID.get(&skill_id).map_or(0, |info| info.duration_ms);
+        self.global_cooldowns.insert(caster, self.time_ms + GLOBAL_COOLDOWN_MS.max(cast_ms));
+        if let Some(timing) = SKILL_TIMING_BY_ID.get(&skill_id) {
+            if timing.cooldown_ms > 0 {
+                self.cooldowns.insert((caster, skill_id), self.time_ms + timing.cooldown_ms);
+            }
+            if timing.duration_ms > 0 {
+                self.skill_expiries.insert((caster, skill_id), self.time_ms + timing.duration_ms);
+                for buff_id in timing.buffs {
+                    self.add_timed_buff_from_source(caster, caster, *buff_id, 1, timing.duration_ms);
+                }
+            }
+        }
+        if self.targets.contains_key(&target) {
+            for pet in self.pets.iter_mut().filter(|pet| pet.owner == caster) {
+                pet.target = target;
+            }
+            if let Some(pet) = PET_BY_ID.get(&skill_id) {
+                self.summon_pet(caster, target, pet);
+            }
+            match CAST_INFO_BY_ID.get(&skill_id) {
+                Some(info) => {
+                    self.casts.insert(caster, ActiveCast::new(caster, target, info, self.time_ms));
+                    self.tick_casts();
+                },
+                None => self.resolve_skill_damage(caster, target, skill_id, self.time_ms),
+            }
+            self.proc_weapon_enchants(caster, target);
+            self.proc_poison(caster, target);
+        }
+    }
+
+    /// Damage of a single hit of `skill_id`, which lands at the cast or at each hit of a cast time or channel.
+    fn resolve_skill_damage(&mut self, caster: UnitId, target: UnitId, skill_id: u32, time_ms: u64) {
+        if let Some(skill) = SKILL_DAMAGE_BY_ID.get(&skill_id) {
+            for hit in self.get_targets_hit(target, skill.size) {
+                match skill.time {
+                    DamageTime::INSTANT => {
+                        let damage = get_skill_damage(skill, self.character(caster), self.target(hit));
+                        self.deal_damage_at(caster, hit, skill.id, skill.damage_type, damage, time_ms);
+                    },
+                    DamageTime::DOT => self.add_damage_over_time(caster, hit, skill),
+                }
+            }
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::enchant::{BERSERKER_ID, CRUSHER_ID, FIERY_WEAPON_ID};
+    use crate::data::major_minor::{BERSERK_MAJOR_ID, BREACH_MAJOR_ID, HEROISM_MAJOR_ID, SORCERY_MAJOR_ID};
+    use crate::data::potion::{ESSENCE_OF_SPELL_POWER_ID, ESSENCE_OF_WEAPON_POWER_ID, HEROISM_POTION_ID};
+    use crate::data::cast::RADIANT_DESTRUCTION;
+    use crate::engine::player::sets::OAKENSOUL_RING_ID;
+    use crate::models::player::ActiveBar;
+    use crate::data::skill_timing::{COMBUSTION_SYNERGY_ID, CRITICAL_SURGE, SYNERGY_COOLDOWN_MS, get_skill_duration_ms};
+    use crate::data::attack::{FIRE_STAFF_HEAVY_ATTACK, FIRE_STAFF_LIGHT_ATTACK};
+    use crate::data::skill::{CATALYST_ID, CRYSTAL_SHARD_ID, DAWNBREAKER_ID, MEDICINAL_USE_ID};
+    use crate::data::poison::{BREACH_POISON_ID, DRAIN_STAMINA_POISON_ID, POISON_STACK_MAXIMUM};
+    use crate::data::item_type::{EnchantType, GearSlot, GearTrait, ItemQuality};
+    use crate::engine::world::damage::get_skill_tooltip;
+    use crate::engine::world::weave::WeaveTiming;
+    use crate::engine::world::target::TARGET_ARMOUR_DEFAULT;
+    use crate::data::armour::SPELL_ATTUNEMENT;
+    use crate::data::major_minor::BRITTLE_MINOR_ID;
+    use crate::data::status_effect::{BURNING, BURNING_ID, CHILLED_ID};
+    use crate::data::pet::{SKELETAL_MAGE, UNSTABLE_FAMILIAR};
+    use crate::data::skill_damage::{BARBED_TRAP, CRYSTAL_FRAGMENTS, KILLERS_BLADE, LIQUID_LIGHTNING, PUNCTURING_SWEEPS, VAMPIRES_BANE};
+    use crate::data::traits::get_jewelry_bloodthirsty_value;
+    use crate::models::player::{GearEnchant, GearPiece};
+
+    use super::*;
+
+    fn jewellery(gear_trait: Option<GearTrait>, set_id: Option<u16>) -> GearPiece {
+        GearPiece {
+            item_id: 194512,
+            effective_level: 66,
+            gear_trait,
+            quality: ItemQuality::Legendary,
+            set_id,
+            enchant: None,
+        }
+    }
+
+    fn weapon(gear_trait: GearTrait, glyph: EnchantType) -> GearPiece {
+        GearPiece {
+            item_id: 172034,
+            effective_level: 66,
+            gear_trait: Some(gear_trait),
+            quality: ItemQuality::Legendary,
+            set_id: None,
+            enchant: Some(GearEnchant {
+                glyph,
+                effective_level: 66,
+                quality: ItemQuality::Legendary,
+            }),
+        }
     }
 
+    #[test]
+    fn crusher_debuffs_target_until_it_expires() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::MainHand, weapon(GearTrait::WeaponInfused, EnchantType::ReduceArmor));
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::new(1));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert!(state.target(1).has_debuff(&CRUSHER_ID));
+        assert_eq!(state.target(1).get_armour(), (TARGET_ARMOUR_DEFAULT as f64 - 1622.0 * 1.3) as u32);
+
+        state.handle_event(Event::Tick { delta_ms: 5000 });
+        assert!(!state.target(1).has_debuff(&CRUSHER_ID));
+        assert_eq!(state.target(1).get_armour(), TARGET_ARMOUR_DEFAULT);
+    }
+
+    #[test]
+    fn weapon_enchant_respects_cooldown() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::MainHand, weapon(GearTrait::WeaponPrecise, EnchantType::FieryWeapon));
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::with_armour(1, 0));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert_eq!(state.get_damage_log().len(), 1);
+        assert_eq!(state.get_damage_log()[0].ability_id, FIERY_WEAPON_ID);
+        assert_eq!(state.get_damage_log()[0].amount, 1742.0);
+
+        state.handle_event(Event::Tick { delta_ms: 4000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert_eq!(state.get_damage_log().len(), 2);
+    }
+
+    #[test]
+    fn berserker_uses_glyph_value_and_absorb_restores_resource() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::MainHand, weapon(GearTrait::WeaponPrecise, EnchantType::Beserker));
+        character.set_gear_piece(&GearSlot::OffHand, weapon(GearTrait::WeaponPrecise, EnchantType::AbsorbMagicka));
+        character.recompute_all_supplemental_state();
+        assert!(character.spend_resource(&PlayerAttributeType::Magicka, 3000));
+        let power = character.get_power();
+        let magicka = character.get_magicka();
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::new(1));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert_eq!(state.character(0).get_power(), power + 348);
+        assert_eq!(state.character(0).get_magicka(), magicka + 1742);
+
+        state.handle_event(Event::Tick { delta_ms: 5000 });
+        assert!(!state.character(0).has_buff(BERSERKER_ID));
+        assert_eq!(state.character(0).get_power(), power);
+    }
+
+    #[test]
+    fn only_active_bar_weapon_enchants_proc() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::MainHandBackup, weapon(GearTrait::WeaponPrecise, EnchantType::FieryWeapon));
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::new(1));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert!(state.get_damage_log().is_empty());
+
+        state.handle_event(Event::BarSwapped { player: 0 });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert_eq!(state.get_damage_log().len(), 1);
+    }
+
+    #[test]
+    fn poison_consumes_charges_and_debuffs_target() {
+        let mut character = Character::new(0);
+        assert!(character.set_poison(&GearSlot::Poison, BREACH_POISON_ID, 2));
+        assert!(!character.set_poison(&GearSlot::MainHand, BREACH_POISON_ID, 2));
+        assert!(!character.set_poison(&GearSlot::BackupPoison, BREACH_POISON_ID, 0));
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::new(1));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert!(state.target(1).has_debuff(&BREACH_MAJOR_ID));
+        assert_eq!(state.target(1).get_armour(), TARGET_ARMOUR_DEFAULT - 5948);
+        assert_eq!(state.character(0).get_poison_charges(&GearSlot::Poison), Some(1));
+
+        state.handle_event(Event::Tick { delta_ms: 5000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert_eq!(state.character(0).get_poison_charges(&GearSlot::Poison), Some(1));
+
+        state.handle_event(Event::Tick { delta_ms: 5000 });
+        assert!(!state.target(1).has_debuff(&BREACH_MAJOR_ID));
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert_eq!(state.character(0).get_poison_charges(&GearSlot::Poison), None);
+        assert!(state.character(0).get_gear_piece(&GearSlot::Poison).is_none());
+
+        state.handle_event(Event::Tick { delta_ms: 10000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert!(!state.target(1).has_debuff(&BREACH_MAJOR_ID));
+    }
+
+    #[test]
+    fn poison_equipped_as_gear_gets_a_full_stack() {
+        let mut character = Character::new(0);
+        assert_eq!(character.get_poison_charges(&GearSlot::Poison), None);
+        character.set_gear_piece(&GearSlot::Poison, GearPiece {
+            item_id: BREACH_POISON_ID,
+            effective_level: 66,
+            gear_trait: None,
+            quality: ItemQuality::Legendary,
+            set_id: None,
+            enchant: None,
+        });
+        assert_eq!(character.get_poison_charges(&GearSlot::Poison), Some(POISON_STACK_MAXIMUM));
+    }
+
+    #[test]
+    fn drain_poison_restores_resource_to_caster() {
+        let mut character = Character::new(0);
+        assert!(character.set_poison(&GearSlot::Poison, DRAIN_STAMINA_POISON_ID, 1));
+        assert!(character.spend_resource(&PlayerAttributeType::Stamina, 3000));
+        let stamina = character.get_stamina();
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::with_armour(1, 0));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert_eq!(state.get_damage_log()[0].ability_id, DRAIN_STAMINA_POISON_ID);
+        assert_eq!(state.character(0).get_stamina(), stamina + 1380);
+    }
+
+    #[test]
+    fn potion_buffs_last_longer_with_medicinal_use() {
+        let mut character = Character::new(0);
+        character.add_buff(MEDICINAL_USE_ID, 1);
+        assert!(character.spend_resource(&PlayerAttributeType::Magicka, 10000));
+        let mut state = GameState::new();
+        state.add_player(character);
+        let magicka = state.character(0).get_magicka();
+
+        state.handle_event(Event::PotionUsed { player: 0, potion_id: ESSENCE_OF_SPELL_POWER_ID });
+        assert!(state.character(0).has_buff(SORCERY_MAJOR_ID));
+        assert_eq!(state.character(0).get_magicka(), magicka + 6048);
+
+        state.handle_event(Event::Tick { delta_ms: 36600 });
+        assert!(state.character(0).has_buff(SORCERY_MAJOR_ID));
+
+        state.handle_event(Event::Tick { delta_ms: 11000 });
+        assert!(!state.character(0).has_buff(SORCERY_MAJOR_ID));
+    }
+
+    #[test]
+    fn potion_cooldown_is_reduced_by_potion_speed() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::Necklace, GearPiece {
+            item_id: 0,
+            effective_level: 66,
+            gear_trait: Some(GearTrait::JewelryInfused),
+            quality: ItemQuality::Legendary,
+            set_id: None,
+            enchant: Some(GearEnchant {
+                glyph: EnchantType::ReducePotionCooldown,
+                effective_level: 66,
+                quality: ItemQuality::Legendary,
+            }),
+        });
+        let mut state = GameState::new();
+        state.add_player(character);
+
+        state.handle_event(Event::PotionUsed { player: 0, potion_id: ESSENCE_OF_WEAPON_POWER_ID });
+        state.handle_event(Event::Tick { delta_ms: 36000 });
+        state.handle_event(Event::PotionUsed { player: 0, potion_id: HEROISM_POTION_ID });
+        assert!(!state.character(0).has_buff(HEROISM_MAJOR_ID));
+
+        // 45s - 5s * 1.6 (infused)
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::PotionUsed { player: 0, potion_id: HEROISM_POTION_ID });
+        assert!(state.character(0).has_buff(HEROISM_MAJOR_ID));
+    }
+
+    #[test]
+    fn buff_stays_while_any_source_remains() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_player(Character::new(2));
+        let power_without_sorcery = state.character_mut(0).get_power();
+
+        state.handle_event(Event::BuffGained { source: Some(2), target: 0, buff_id: SORCERY_MAJOR_ID, stacks: 1 });
+        state.handle_event(Event::BuffGained { source: None, target: 0, buff_id: SORCERY_MAJOR_ID, stacks: 1 });
+        let power_with_sorcery = state.character_mut(0).get_power();
+        assert!(power_with_sorcery > power_without_sorcery);
+
+        state.handle_event(Event::BuffFaded { source: Some(2), target: 0, buff_id: SORCERY_MAJOR_ID });
+        assert!(state.character(0).has_buff(SORCERY_MAJOR_ID));
+        assert_eq!(state.character_mut(0).get_power(), power_with_sorcery);
+
+        state.handle_event(Event::BuffFaded { source: None, target: 0, buff_id: SORCERY_MAJOR_ID });
+        assert!(!state.character(0).has_buff(SORCERY_MAJOR_ID));
+        assert_eq!(state.character_mut(0).get_power(), power_without_sorcery);
+    }
+
+    #[test]
+    fn timed_buffs_expire_per_source() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_player(Character::new(2));
+
+        state.add_timed_buff_from_source(2, 0, SORCERY_MAJOR_ID, 1, 5000);
+        state.add_timed_buff(0, SORCERY_MAJOR_ID, 1, 10000);
+
+        state.handle_event(Event::Tick { delta_ms: 5000 });
+        assert!(state.character(0).has_buff(SORCERY_MAJOR_ID));
+
+        state.handle_event(Event::Tick { delta_ms: 5000 });
+        assert!(!state.character(0).has_buff(SORCERY_MAJOR_ID));
+    }
+
+    #[test]
+    fn hardening_shields_the_wielder_until_it_expires() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::MainHand, weapon(GearTrait::WeaponInfused, EnchantType::DamageShield));
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::new(1));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: 0 });
+        assert!(state.character(0).get_shield() > 0.0);
+
+        state.handle_event(Event::Tick { delta_ms: WEAPON_ENCHANT_DURATION_MS });
+        assert_eq!(state.character(0).get_shield(), 0.0);
+    }
+
+    #[test]
+    fn heals_over_time_tick_until_they_expire() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.character_mut(0).take_hit(&IncomingHit::new(100000.0, DamageType::OBLIVION));
+        assert_eq!(state.character(0).get_health(), 0);
+
+        let heal = Heal::new(1, PlayerAttributeType::Magicka, 0.0, 1.0);
+        state.add_heal_over_time(0, 0, heal.clone(), 2000, 10000);
+        state.handle_event(Event::Tick { delta_ms: 5000 });
+        assert_eq!(state.get_heal_log().len(), 2);
+        state.handle_event(Event::Tick { delta_ms: 10000 });
+        assert_eq!(state.get_heal_log().len(), 5);
+        assert_eq!(state.get_heal_log()[4].time_ms, 10000);
+
+        let tick = heal.get_tooltip(state.character(0));
+        assert!((state.get_total_healing(0) - 5.0 * tick).abs() < 1.0);
+        assert_eq!(state.character(0).get_health(), state.get_total_healing(0) as u32);
+    }
+
+    #[test]
+    fn elemental_damage_procs_status_effects_with_secondary_debuffs() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::new(1));
+
+        for _ in 0..9 {
+            state.deal_damage(0, 1, 0, DamageType::COLD, 1000.0);
+        }
+        assert!(state.get_status_effects(1).is_empty());
+        for _ in 0..2 {
+            state.deal_damage(0, 1, 0, DamageType::COLD, 1000.0);
+        }
+        assert_eq!(state.get_status_effects(1), vec![StatusEffect::Chilled]);
+        assert!(state.target(1).has_debuff(&CHILLED_ID));
+        assert!(state.target(1).has_debuff(&BRITTLE_MINOR_ID));
+
+        state.handle_event(Event::Tick { delta_ms: 4000 });
+        assert!(state.get_status_effects(1).is_empty());
+        assert!(!state.target(1).has_debuff(&BRITTLE_MINOR_ID));
+    }
+
+    #[test]
+    fn burning_ticks_damage_until_it_expires() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::with_armour(1, 0));
+
+        state.handle_event(Event::StatusEffectApplied { source: 0, target: 1, effect: StatusEffect::Burning });
+        state.handle_event(Event::Tick { delta_ms: 10000 });
+        let ticks: Vec<&DamageRecord> = state.get_damage_log().iter().filter(|record| record.ability_id == BURNING_ID).collect();
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(ticks[1].time_ms, 4000);
+        assert_eq!(ticks[0].amount, BURNING.tick_damage.unwrap());
+    }
+
+    #[test]
+    fn afflicted_players_get_double_spell_attunement() {
+        let mut character = Character::new(0);
+        character.add_buff(SPELL_ATTUNEMENT.id, 1);
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::new(1));
+        let armour = state.character(0).get_armour(&DamageType::FIRE);
+
+        state.handle_event(Event::StatusEffectApplied { source: 1, target: 0, effect: StatusEffect::Chilled });
+        assert!(state.character(0).has_buff(BRITTLE_MINOR_ID));
+        assert_eq!(state.character(0).get_armour(&DamageType::FIRE), armour + 2310);
+
+        state.handle_event(Event::Tick { delta_ms: 4000 });
+        assert_eq!(state.character(0).get_armour(&DamageType::FIRE), armour);
+    }
+
+    fn skill_ticks(state: &GameState, skill_id: ID) -> Vec<&DamageRecord> {
+        state.get_damage_log().iter().filter(|record| record.ability_id == skill_id).collect()
+    }
+
+    #[test]
+    fn damage_over_time_ticks_at_its_frequency_until_it_expires() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::with_armour(1, 0));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: BARBED_TRAP.id });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: LIQUID_LIGHTNING.id });
+        assert!(skill_ticks(&state, BARBED_TRAP.id).is_empty());
+        assert_eq!(skill_ticks(&state, LIQUID_LIGHTNING.id).len(), 1);
+
+        state.handle_event(Event::Tick { delta_ms: 30000 });
+        let ticks = skill_ticks(&state, BARBED_TRAP.id);
+        assert_eq!(ticks.len(), 10);
+        assert_eq!(ticks[0].time_ms, 2000);
+        assert_eq!(ticks[9].time_ms, 20000);
+        let ticks = skill_ticks(&state, LIQUID_LIGHTNING.id);
+        assert_eq!(ticks.len(), 10);
+        assert_eq!(ticks[9].time_ms, 10000);
+        assert!(state.get_damages_over_time(1).is_empty());
+    }
+
+    #[test]
+    fn snapshot_damage_over_time_keeps_the_stats_at_the_cast() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::with_armour(1, 0));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: VAMPIRES_BANE.id });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: LIQUID_LIGHTNING.id });
+        state.handle_event(Event::Tick { delta_ms: 2000 });
+        state.add_buff(0, SORCERY_MAJOR_ID, 1);
+        state.handle_event(Event::Tick { delta_ms: 2000 });
+
+        let snapshot = skill_ticks(&state, VAMPIRES_BANE.id);
+        assert_eq!(snapshot[0].amount, snapshot[1].amount);
+        let dynamic = skill_ticks(&state, LIQUID_LIGHTNING.id);
+        assert!(dynamic[4].amount > dynamic[0].amount);
+        assert_eq!(dynamic[4].amount, get_skill_tooltip(&LIQUID_LIGHTNING, state.character(0)));
+    }
+
+    #[test]
+    fn recasting_damage_over_time_refreshes_or_overwrites() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::new(1));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: VAMPIRES_BANE.id });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: BARBED_TRAP.id });
+        state.handle_event(Event::Tick { delta_ms: 2000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: VAMPIRES_BANE.id });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: BARBED_TRAP.id });
+
+        let dots = state.get_damages_over_time(1);
+        assert_eq!(dots.len(), 2);
+        let refreshed = dots.iter().find(|dot| dot.skill.id == VAMPIRES_BANE.id).unwrap();
+        assert_eq!(refreshed.next_tick_ms, 6000);
+        assert_eq!(refreshed.expires_at_ms, 3000 + VAMPIRES_BANE.duration_ms);
+        let overwritten = dots.iter().find(|dot| dot.skill.id == BARBED_TRAP.id).unwrap();
+        assert_eq!(overwritten.next_tick_ms, 6000);
+        assert_eq!(overwritten.expires_at_ms, 4000 + BARBED_TRAP.duration_ms);
+    }
+
+    #[test]
+    fn area_of_effect_skills_hit_every_enemy_up_to_the_cap() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        for id in 1..=8 {
+            state.add_target(Target::new(id));
+        }
+        state.target_mut(5).add_debuff(BREACH_MAJOR_ID, Debuff { stacks: 1, value: None, expires_at_ms: None });
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 5, skill_id: CRYSTAL_FRAGMENTS.id });
+        assert_eq!(skill_ticks(&state, CRYSTAL_FRAGMENTS.id).len(), 1);
+
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 5, skill_id: PUNCTURING_SWEEPS.id });
+        let hits = skill_ticks(&state, PUNCTURING_SWEEPS.id);
+        assert_eq!(hits.len(), AOE_TARGET_CAP);
+        let targets: Vec<UnitId> = hits.iter().map(|record| record.target).collect();
+        assert_eq!(targets, vec![5, 1, 2, 3, 4, 6]);
+        assert!(hits[0].amount > hits[1].amount);
+        assert_eq!(hits[1].amount, hits[2].amount);
+
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 8, skill_id: LIQUID_LIGHTNING.id });
+        assert_eq!(state.get_damages_over_time(8).len(), 1);
+        assert!(state.get_damages_over_time(7).is_empty());
+        assert_eq!(state.get_total_damage_to(7), 0.0);
+        assert!(state.get_total_damage_to(1) > 0.0);
+    }
+
+    #[test]
+    fn damage_done_applies_to_every_area_of_effect_hit() {
+        let mut states = Vec::new();
+        for berserk in [false, true] {
+            let mut state = GameState::new();
+            state.add_player(Character::new(0));
+            state.add_target(Target::with_armour(1, 0));
+            state.add_target(Target::with_armour(2, 0));
+            if berserk {state.add_buff(0, BERSERK_MAJOR_ID, 1)}
+            state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: PUNCTURING_SWEEPS.id });
+            states.push(state);
+        }
+
+        let (base, berserk) = (skill_ticks(&states[0], PUNCTURING_SWEEPS.id), skill_ticks(&states[1], PUNCTURING_SWEEPS.id));
+        assert_eq!(berserk.len(), 2);
+        for (base, berserk) in base.iter().zip(berserk.iter()) {
+            assert_eq!(berserk.amount, base.amount * 1.1);
+        }
+    }
+
+    #[test]
+    fn pets_attack_the_owners_target_with_the_owners_stats() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::with_armour(1, 0));
+        state.add_target(Target::with_armour(2, 0));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: UNSTABLE_FAMILIAR.id });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: SKELETAL_MAGE.id });
+        let familiar = state.get_pets(0)[0].id;
+        assert_eq!(state.get_owner(familiar), 0);
+
+        state.handle_event(Event::Tick { delta_ms: 4000 });
+        let attacks = skill_ticks(&state, UNSTABLE_FAMILIAR.attack.id);
+        assert_eq!(attacks.len(), 2);
+        assert_eq!(attacks[0].source, familiar);
+        assert_eq!(attacks[0].amount, get_skill_tooltip(UNSTABLE_FAMILIAR.attack, state.character(0)));
+
+        state.add_buff(0, BERSERK_MAJOR_ID, 1);
+        state.handle_event(Event::SkillUsed { caster: 0, target: 2, skill_id: 0 });
+        state.handle_event(Event::Tick { delta_ms: 2000 });
+        let attacks = skill_ticks(&state, UNSTABLE_FAMILIAR.attack.id);
+        assert_eq!(attacks[2].target, 2);
+        assert!((attacks[2].amount - attacks[0].amount * 1.1).abs() < 0.01);
+
+        state.handle_event(Event::Tick { delta_ms: 20000 });
+        assert_eq!(state.get_pets(0).len(), 1);
+        assert_eq!(skill_ticks(&state, SKELETAL_MAGE.attack.id).len(), 10);
+        assert_eq!(state.get_total_pet_damage(0), state.get_total_damage(familiar) + state.get_total_damage(PET_UNIT_ID_START + 1));
+        assert_eq!(state.get_total_damage(0), 0.0);
+    }
+
+    #[test]
+    fn execute_skills_and_bloodthirsty_scale_with_target_health() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::Necklace, jewellery(Some(GearTrait::JewelryBloodthirsty), None));
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::with_armour(1, 0).with_health(100000));
+        let power = state.character(0).get_power();
+        assert_eq!(state.character(0).get_power_against(0.95), power);
+        assert_eq!(state.character(0).get_power_against(0.5), power + get_jewelry_bloodthirsty_value(&ItemQuality::Legendary) as u32);
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: KILLERS_BLADE.id });
+        let full = state.get_damage_log()[0].amount;
+        assert_eq!(full, get_skill_tooltip(&KILLERS_BLADE, state.character(0)));
+        assert_eq!(state.target(1).get_health(), 100000.0 - full);
+
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.target_mut(1).take_damage(60000.0 - full);
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: KILLERS_BLADE.id });
+        let bloodthirsty = KILLERS_BLADE.power_coefficient * get_jewelry_bloodthirsty_value(&ItemQuality::Legendary);
+        assert!((state.get_damage_log()[1].amount - (full + bloodthirsty) * 5.0).abs() < 0.1);
+        assert!(!state.get_damage_log()[1].execute);
+
+        state.target_mut(1).take_damage(25000.0);
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: KILLERS_BLADE.id });
+        assert!(state.get_damage_log()[2].execute);
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+
+        let dps = state.get_dps_by_phase(0, 1);
+        let log = state.get_damage_log();
+        assert_eq!(dps.pre_execute, (log[0].amount + log[1].amount) / 2.0);
+        assert_eq!(dps.execute, log[2].amount);
+    }
+
+    #[test]
+    fn execute_phase_is_per_target_and_dead_targets_take_no_damage() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::with_armour(1, 0).with_health(100000));
+        state.add_target(Target::with_armour(2, 0));
+        state.target_mut(1).take_damage(90000.0);
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: PUNCTURING_SWEEPS.id });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        let hits = skill_ticks(&state, PUNCTURING_SWEEPS.id);
+        assert!(hits[0].execute);
+        assert!(!hits[1].execute);
+        let on_add = state.get_dps_by_phase(0, 2);
+        assert_eq!(on_add.pre_execute, hits[1].amount);
+        assert_eq!(on_add.execute, 0.0);
+        assert_eq!(state.get_dps_by_phase(0, 1).pre_execute, 0.0);
+
+        state.target_mut(1).take_damage(100000.0);
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: PUNCTURING_SWEEPS.id });
+        let hits = skill_ticks(&state, PUNCTURING_SWEEPS.id);
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[2].target, 2);
+    }
+
+    #[test]
+    fn ultimate_is_generated_and_spent_on_ultimates() {
+        let mut character = Character::new(0);
+        character.add_buff(CATALYST_ID, 1);
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::new(1));
+
+        for _ in 0..10 {
+            state.handle_event(Event::LightAttack { caster: 0, target: 1 });
+        }
+        state.handle_event(Event::HeavyAttack { caster: 0, target: 1 });
+        assert_eq!(state.character(0).get_ultimate(), 33);
+        state.handle_event(Event::PotionUsed { player: 0, potion_id: ESSENCE_OF_SPELL_POWER_ID });
+        assert_eq!(state.character(0).get_ultimate(), 53);
+
+        state.add_buff(0, HEROISM_MAJOR_ID, 1);
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::Tick { delta_ms: 2000 });
+        assert_eq!(state.character(0).get_ultimate(), 59);
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: DAWNBREAKER_ID });
+        assert_eq!(state.character(0).get_ultimate(), 59);
+        assert_eq!(state.character(0).get_warnings(), &vec![Warning::NotEnoughUltimate { skill_id: DAWNBREAKER_ID, cost: 125, ultimate: 59 }]);
+
+        state.handle_event(Event::Tick { delta_ms: 33000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: DAWNBREAKER_ID });
+        assert_eq!(state.character(0).get_ultimate(), 59 + 66 - 125);
+    }
+
+    #[test]
+    fn light_and_heavy_attacks_depend_on_the_main_hand() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::MainHand, GearPiece {
+            item_id: 172042,
+            effective_level: 66,
+            gear_trait: None,
+            quality: ItemQuality::Legendary,
+            set_id: None,
+            enchant: None,
+        });
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::with_armour(1, 0));
+        assert_eq!(state.character(0).get_light_attack(), &FIRE_STAFF_LIGHT_ATTACK);
+
+        state.handle_event(Event::LightAttack { caster: 0, target: 1 });
+        let light = &state.get_damage_log()[0];
+        assert_eq!(light.ability_id, FIRE_STAFF_LIGHT_ATTACK.id);
+        assert_eq!(light.damage_type, DamageType::FIRE);
+        assert_eq!(light.amount, get_attack_damage(&FIRE_STAFF_LIGHT_ATTACK, state.character(0), state.target(1)));
+
+        state.character_mut(0).spend_resource(&PlayerAttributeType::Magicka, 5000);
+        let magicka = state.character(0).get_magicka();
+        state.channel_heavy_attack(0, 1);
+        assert_eq!(state.time_ms(), FIRE_STAFF_HEAVY_ATTACK.channel_ms);
+        assert_eq!(state.get_damage_log()[1].ability_id, FIRE_STAFF_HEAVY_ATTACK.id);
+        assert_eq!(state.character(0).get_magicka(), magicka + FIRE_STAFF_HEAVY_ATTACK.restore);
+
+        state.add_buff(0, SORCERY_MAJOR_ID, 1);
+        let mut timing = WeaveTiming::new(50);
+        for _ in 0..3 {
+            state.weave(0, 1, CRYSTAL_FRAGMENTS.id, &timing);
+        }
+        assert_eq!(state.time_ms(), FIRE_STAFF_HEAVY_ATTACK.channel_ms + 3 * timing.get_cycle_ms());
+        let lights = skill_ticks(&state, FIRE_STAFF_LIGHT_ATTACK.id);
+        assert_eq!(lights.len(), 4);
+        assert!(lights[1].amount > lights[0].amount);
+        assert_eq!(lights[1].amount, get_attack_damage(&FIRE_STAFF_LIGHT_ATTACK, state.character(0), state.target(1)));
+        assert_eq!(skill_ticks(&state, CRYSTAL_FRAGMENTS.id).len(), 3);
+
+        timing.light_attack_ms += 200;
+        let start_ms = state.time_ms();
+        state.weave(0, 1, CRYSTAL_FRAGMENTS.id, &timing);
+        assert_eq!(state.time_ms() - start_ms, 500 + 1000 + 50);
+    }
+
+    #[test]
+    fn casts_and_channels_hold_the_global_cooldown_until_they_end() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::with_armour(1, 0));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: CRYSTAL_SHARD_ID });
+        assert!(skill_ticks(&state, CRYSTAL_SHARD_ID).is_empty());
+        assert_eq!(state.get_global_cooldown_remaining_ms(0), GLOBAL_COOLDOWN_MS);
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: CRYSTAL_FRAGMENTS.id });
+        assert_eq!(state.character(0).get_warnings(), &vec![Warning::SkillUsedWhileCasting { skill_id: CRYSTAL_FRAGMENTS.id, casting: CRYSTAL_SHARD_ID }]);
+        state.handle_event(Event::Tick { delta_ms: 800 });
+        assert_eq!(skill_ticks(&state, CRYSTAL_SHARD_ID).len(), 1);
+        assert!(!state.is_casting(0));
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: CRYSTAL_FRAGMENTS.id });
+        assert_eq!(state.character(0).get_warnings().last(), Some(&Warning::GlobalCooldown { skill_id: CRYSTAL_FRAGMENTS.id, remaining_ms: 200 }));
+        assert!(skill_ticks(&state, CRYSTAL_FRAGMENTS.id).is_empty());
+
+        state.handle_event(Event::Tick { delta_ms: 200 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: RADIANT_DESTRUCTION.id });
+        assert_eq!(skill_ticks(&state, RADIANT_DESTRUCTION.id).len(), 1);
+        assert_eq!(state.get_global_cooldown_remaining_ms(0), RADIANT_DESTRUCTION.duration_ms);
+        state.handle_event(Event::Tick { delta_ms: 3000 });
+        let ticks: Vec<u64> = skill_ticks(&state, RADIANT_DESTRUCTION.id).iter().map(|record| record.time_ms).collect();
+        assert_eq!(ticks, vec![1000, 2000, 3000, 4000]);
+        assert!(!state.is_casting(0));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: RADIANT_DESTRUCTION.id });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::BarSwapped { player: 0 });
+        assert!(!state.is_casting(0));
+        assert_eq!(state.get_global_cooldown_remaining_ms(0), 0);
+        state.handle_event(Event::Tick { delta_ms: 2000 });
+        assert_eq!(skill_ticks(&state, RADIANT_DESTRUCTION.id).len(), 6);
+    }
+
+    #[test]
+    fn skills_on_cooldown_are_rejected_and_durations_drive_recasts() {
+        let mut state = GameState::new();
+        state.add_player(Character::new(0));
+        state.add_target(Target::with_armour(1, 0));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: CRITICAL_SURGE.id });
+        state.handle_event(Event::Tick { delta_ms: 1000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: VAMPIRES_BANE.id });
+        assert_eq!(get_skill_duration_ms(CRITICAL_SURGE.id), Some(33000));
+        assert_eq!(get_skill_duration_ms(VAMPIRES_BANE.id), Some(VAMPIRES_BANE.duration_ms));
+        assert!(state.character(0).get_buffs().contains_key(&SORCERY_MAJOR_ID));
+        assert!(!state.should_recast(0, 1, CRITICAL_SURGE.id, 3000));
+
+        state.handle_event(Event::Tick { delta_ms: 28000 });
+        assert!(!state.should_recast(0, 1, CRITICAL_SURGE.id, 3000));
+        assert!(state.should_recast(0, 1, VAMPIRES_BANE.id, 3000));
+        state.handle_event(Event::Tick { delta_ms: 2000 });
+        assert!(state.should_recast(0, 1, CRITICAL_SURGE.id, 3000));
+        state.handle_event(Event::Tick { delta_ms: 3000 });
+        assert!(!state.character(0).get_buffs().contains_key(&SORCERY_MAJOR_ID));
+        assert_eq!(state.get_skill_remaining_ms(0, 1, CRITICAL_SURGE.id), 0);
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: COMBUSTION_SYNERGY_ID });
+        assert!(!state.should_recast(0, 1, COMBUSTION_SYNERGY_ID, 0));
+        state.handle_event(Event::Tick { delta_ms: 5000 });
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: COMBUSTION_SYNERGY_ID });
+        assert_eq!(state.character(0).get_warnings(), &vec![Warning::SkillOnCooldown { skill_id: COMBUSTION_SYNERGY_ID, remaining_ms: SYNERGY_COOLDOWN_MS - 5000 }]);
+        state.handle_event(Event::Tick { delta_ms: SYNERGY_COOLDOWN_MS - 5000 });
+        assert!(state.should_recast(0, 1, COMBUSTION_SYNERGY_ID, 0));
+    }
+
+    #[test]
+    fn oakensoul_grants_major_buffs_and_blocks_bar_swaps() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::Ring1, jewellery(None, Some(OAKENSOUL_RING_ID)));
+        let mut state = GameState::new();
+        state.add_player(character);
+        assert!(state.character(0).get_buffs().contains_key(&SORCERY_MAJOR_ID));
+        assert!(state.character(0).get_buffs().contains_key(&HEROISM_MAJOR_ID));
+
+        state.handle_event(Event::BarSwapped { player: 0 });
+        assert_eq!(state.character(0).get_active_bar(), &ActiveBar::Primary);
+        assert_eq!(state.character(0).get_warnings(), &vec![Warning::BarSwapBlocked { set_id: OAKENSOUL_RING_ID }]);
+        assert!(state.character_mut(0).swap_bars(Some(&ActiveBar::Primary)));
+        assert!(!state.character_mut(0).swap_bars(Some(&ActiveBar::Backup)));
+    }
+
+    #[test]
+    fn oakensoul_buffs_outlast_timed_casts_of_the_same_buff() {
+        let mut character = Character::new(0);
+        character.set_gear_piece(&GearSlot::Ring1, jewellery(None, Some(OAKENSOUL_RING_ID)));
+        let mut state = GameState::new();
+        state.add_player(character);
+        state.add_target(Target::new(1));
+
+        state.handle_event(Event::SkillUsed { caster: 0, target: 1, skill_id: CRITICAL_SURGE.id });
+        state.handle_event(Event::Tick { delta_ms: 34000 });
+        assert!(state.character(0).has_buff(SORCERY_MAJOR_ID));
+        assert!(state.character(0).get_buff_sources(&SORCERY_MAJOR_ID).contains(&get_set_source(OAKENSOUL_RING_ID)));
+    }
 }
@@ -0,0 +1,36 @@
+use crate::engine::ID;
+use crate::engine::player::healing::Heal;
+use crate::engine::world::event::UnitId;
+
+#[derive(Debug, PartialEq)]
+pub struct HealRecord {
+    pub time_ms: u64,
+    pub source: UnitId,
+    pub target: UnitId,
+    pub ability_id: ID,
+    /// Health restored.
+    pub amount: f32,
+    pub overheal: f32,
+}
+
+/// A heal which ticks every `interval_ms` until `expires_at_ms`, using the caster's stats at each tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealOverTime {
+    pub source: UnitId,
+    pub target: UnitId,
+    pub heal: Heal,
+    pub interval_ms: u64,
+    pub next_tick_ms: u64,
+    pub expires_at_ms: u64,
+}
+
+impl HealOverTime {
+    /// Whether another tick is due by `time_ms`.
+    pub fn is_due(&self, time_ms: u64) -> bool {
+        self.next_tick_ms <= time_ms && self.next_tick_ms <= self.expires_at_ms
+    }
+
+    pub fn has_expired(&self, time_ms: u64) -> bool {
+        self.expires_at_ms <= time_ms
+    }
+}
@@ -1 +1,9 @@
-pub mod event;
\ No newline at end of file
+pub mod cast;
+pub mod damage;
+pub mod damage_over_time;
+pub mod event;
+pub mod heal;
+pub mod pet;
+pub mod status_effect;
+pub mod target;
+pub mod weave;
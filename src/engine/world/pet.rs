@@ -0,0 +1,27 @@
+use crate::data::pet::PetData;
+use crate::engine::world::event::UnitId;
+
+/// Unit ids of pets start here so they never collide with players or targets.
+pub const PET_UNIT_ID_START: UnitId = 1_000_000;
+
+/// A pet owned by a player, attacking the owner's current target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pet {
+    pub id: UnitId,
+    pub owner: UnitId,
+    pub data: &'static PetData,
+    pub target: UnitId,
+    pub next_attack_ms: u64,
+    pub expires_at_ms: Option<u64>,
+}
+
+impl Pet {
+    /// Whether another attack is due by `time_ms`.
+    pub fn is_due(&self, time_ms: u64) -> bool {
+        self.next_attack_ms <= time_ms && self.expires_at_ms.is_none_or(|expires_at| self.next_attack_ms <= expires_at)
+    }
+
+    pub fn has_expired(&self, time_ms: u64) -> bool {
+        self.expires_at_ms.is_some_and(|expires_at| expires_at <= time_ms)
+    }
+}
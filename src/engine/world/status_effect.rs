@@ -0,0 +1,23 @@
+use crate::data::status_effect::StatusEffect;
+use crate::engine::world::event::UnitId;
+
+/// A status effect on a target or player. Only one instance of each status effect can be on a unit, reapplying it replaces the source and refreshes the duration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveStatusEffect {
+    pub source: UnitId,
+    pub target: UnitId,
+    pub effect: StatusEffect,
+    pub next_tick_ms: u64,
+    pub expires_at_ms: u64,
+}
+
+impl ActiveStatusEffect {
+    /// Whether another damage tick is due by `time_ms`. Status effects which don't deal damage never tick.
+    pub fn is_due(&self, time_ms: u64) -> bool {
+        self.effect.get_data().tick_damage.is_some() && self.next_tick_ms <= time_ms && self.next_tick_ms <= self.expires_at_ms
+    }
+
+    pub fn has_expired(&self, time_ms: u64) -> bool {
+        self.expires_at_ms <= time_ms
+    }
+}
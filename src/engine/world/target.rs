@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use crate::data::armour::ARMOUR_ALL_BY_ID;
+use crate::data::damage_modifier::DAMAGE_TAKEN_BY_ID;
+use crate::data::enchant::CRUSHER_ID;
+use crate::engine::{ID, STACKS};
+use crate::engine::world::event::UnitId;
+
+/// Physical and spell resistance of a trial dummy.
+pub const TARGET_ARMOUR_DEFAULT: u32 = 18200;
+/// Health of a trial dummy.
+pub const TARGET_HEALTH_DEFAULT: u32 = 21_000_000;
+/// Fraction of max health below which a target is in the execute phase.
+pub const EXECUTE_PHASE_THRESHOLD: f32 = 0.2;
+
+pub struct Debuff {
+    pub stacks: STACKS,
+    /// Overrides the data value of the debuff, for debuffs which scale with their source such as Crusher.
+    pub value: Option<f64>,
+    pub expires_at_ms: Option<u64>,
+}
+
+pub struct Target {
+    id: UnitId,
+    base_armour: u32,
+    max_health: u32,
+    damage_taken: f32,
+    debuffs: HashMap<ID, Debuff>,
+}
+
+impl Target {
+    pub fn new(id: UnitId) -> Self {
+        Self::with_armour(id, TARGET_ARMOUR_DEFAULT)
+    }
+
+    pub fn with_armour(id: UnitId, armour: u32) -> Self {
+        Self {
+            id,
+            base_armour: armour,
+            max_health: TARGET_HEALTH_DEFAULT,
+            damage_taken: 0.0,
+            debuffs: HashMap::new(),
+        }
+    }
+
+    pub fn with_health(mut self, max_health: u32) -> Self {
+        self.max_health = max_health;
+        self
+    }
+
+    pub fn id(&self) -> UnitId {
+        self.id
+    }
+
+    pub fn get_max_health(&self) -> u32 {
+        self.max_health
+    }
+
+    pub fn get_health(&self) -> f32 {
+        (self.max_health as f32 - self.damage_taken).max(0.0)
+    }
+
+    /// Remaining health as a fraction of max health.
+    pub fn get_health_fraction(&self) -> f32 {
+        if self.max_health == 0 {return 0.0}
+        self.get_health() / self.max_health as f32
+    }
+
+    pub fn is_in_execute(&self) -> bool {
+        self.get_health_fraction() < EXECUTE_PHASE_THRESHOLD
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.get_health() <= 0.0
+    }
+
+    pub fn take_damage(&mut self, amount: f32) {
+        self.damage_taken += amount.max(0.0);
+    }
+
+    pub fn add_debuff(&mut self, id: ID, debuff: Debuff) {
+        self.debuffs.insert(id, debuff);
+    }
+
+    pub fn remove_debuff(&mut self, id: &ID) {
+        self.debuffs.remove(id);
+    }
+
+    pub fn has_debuff(&self, id: &ID) -> bool {
+        self.debuffs.contains_key(id)
+    }
+
+    pub fn get_debuff(&self, id: &ID) -> Option<&Debuff> {
+        self.debuffs.get(id)
+    }
+
+    pub fn get_debuffs(&self) -> &HashMap<ID, Debuff> {
+        &self.debuffs
+    }
+
+    /// Removes every debuff that has run out by `time_ms`.
+    pub fn expire_debuffs(&mut self, time_ms: u64) {
+        self.debuffs.retain(|_, debuff| debuff.expires_at_ms.is_none_or(|expires_at| expires_at > time_ms));
+    }
+
+    pub fn get_armour(&self) -> u32 {
+        let mut armour = self.base_armour as f64;
+        for (id, debuff) in &self.debuffs {
+            if let Some(buff) = ARMOUR_ALL_BY_ID.get(id) {
+                armour += debuff.value.unwrap_or(buff.value + buff.value_per_stack * debuff.stacks as f64);
+            } else if *id == CRUSHER_ID {
+                armour += debuff.value.unwrap_or(0.0);
+            }
+        }
+        armour.max(0.0) as u32
+    }
+
+    /// Multiplier on damage taken from Vulnerability and Protection.
+    pub fn get_damage_taken_multiplier(&self) -> f32 {
+        let mut modifier = 0.0;
+        for (id, debuff) in &self.debuffs {
+            if let Some(buff) = DAMAGE_TAKEN_BY_ID.get(id) {
+                modifier += buff.value + buff.value_per_stack * debuff.stacks as f64;
+            }
+        }
+        1.0 + modifier as f32 / 100.0
+    }
+}
@@ -0,0 +1,27 @@
+use crate::data::attack::GLOBAL_COOLDOWN_MS;
+
+/// Time a light attack needs before a skill can cancel its animation.
+pub const LIGHT_ATTACK_WINDOW_DEFAULT_MS: u64 = 300;
+
+/// Timing of a light attack woven in before each skill.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeaveTiming {
+    pub light_attack_ms: u64,
+    /// Delay added to every weave by the player's connection.
+    pub latency_ms: u64,
+}
+
+impl WeaveTiming {
+    pub fn new(latency_ms: u64) -> Self {
+        Self {
+            light_attack_ms: LIGHT_ATTACK_WINDOW_DEFAULT_MS,
+            latency_ms,
+        }
+    }
+
+    /// Time from one weave to the next for an instant skill. The skill cancels the light attack once it lands, so slower light attacks
+    /// delay every skill, then the next light attack waits for the global cooldown. Latency delays each weave.
+    pub fn get_cycle_ms(&self) -> u64 {
+        self.light_attack_ms + GLOBAL_COOLDOWN_MS + self.latency_ms
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Armour {
     additive: u32,
 }
@@ -23,7 +23,7 @@ impl Armour {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Penetration {
     additive: u32,
 }
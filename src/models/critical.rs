@@ -2,6 +2,7 @@ use crate::models::{CRITICAL_CHANCE_DEFAULT, CRITICAL_DAMAGE_DEFAULT, CRITICAL_D
 
 pub const CRIT_DAMAGE_SCALE: u16 = 66;
 
+#[derive(Clone)]
 pub struct CriticalDamage {
     additive_scaled: u16,
 }
@@ -19,6 +20,11 @@ impl CriticalDamage {
             + (CRITICAL_DAMAGE_DEFAULT * CRIT_DAMAGE_SCALE)
     }
 
+    /// Critical damage every character has, as a percentage.
+    pub fn get_base(&self) -> u16 {
+        CRITICAL_DAMAGE_DEFAULT
+    }
+
     pub fn add_percent(&mut self, percent: u16) {
         self.additive_scaled += percent * CRIT_DAMAGE_SCALE;
     }
@@ -36,6 +42,7 @@ impl Default for CriticalDamage {
     }
 }
 
+#[derive(Clone)]
 pub struct CriticalChance {
     additive: u32,
 }
@@ -46,8 +53,27 @@ impl CriticalChance {
     }
 
     pub fn calculate_with_level(&self, level: u8) -> f32 {
+        self.calculate_uncapped_with_level(level).min(1.0)
+    }
+
+    pub fn calculate_uncapped(&self) -> f32 {
+        self.calculate_uncapped_with_level(EFFECTIVE_LEVEL)
+    }
+
+    pub fn calculate_uncapped_with_level(&self, level: u8) -> f32 {
         let level_const = 2 * level as u32 * (100 + level as u32);
-        ((self.additive as f32 / level_const as f32) + CRITICAL_CHANCE_DEFAULT).min(1.0)
+        (self.additive as f32 / level_const as f32) + CRITICAL_CHANCE_DEFAULT
+    }
+
+    /// Critical chance every character has, before any rating.
+    pub fn get_base(&self) -> f32 {
+        CRITICAL_CHANCE_DEFAULT
+    }
+
+    /// Critical chance granted by `rating` alone, without the base chance.
+    pub fn get_chance_from_rating(rating: u32) -> f32 {
+        let level_const = 2 * EFFECTIVE_LEVEL as u32 * (100 + EFFECTIVE_LEVEL as u32);
+        rating as f32 / level_const as f32
     }
 
     pub fn add_to_additive(&mut self, value: u32) {
@@ -71,6 +97,35 @@ impl Default for CriticalChance {
     }
 }
 
+#[derive(Default, Clone)]
+pub struct CriticalResistance {
+    additive: u32,
+}
+
+impl CriticalResistance {
+    /// Fraction of critical damage negated, using the same rating conversion as critical chance.
+    pub fn calculate(&self) -> f32 {
+        self.calculate_with_level(EFFECTIVE_LEVEL)
+    }
+
+    pub fn calculate_with_level(&self, level: u8) -> f32 {
+        let level_const = 2 * level as u32 * (100 + level as u32);
+        self.additive as f32 / level_const as f32
+    }
+
+    pub fn add_to_additive(&mut self, value: u32) {
+        self.additive += value;
+    }
+
+    pub fn get_raw(&self) -> u32 {
+        self.additive
+    }
+
+    pub fn reset(&mut self) {
+        self.additive = 0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,7 +148,8 @@ mod tests {
         + 320 // precision cp
         + 657 // slimecraw 1pc
         + 1579); // precise weapon
-        assert_eq!(crit_chance.calculate() * 100.0, 48.421867);
+        // 8421 rating / (2 * 66 * 166) + 10% base; the old 48.421867 only matched 8419 rating
+        assert_eq!(crit_chance.calculate() * 100.0, 48.430996);
     }
 
     #[test]
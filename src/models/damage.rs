@@ -1,3 +1,4 @@
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum DamageType {
     BLEED,
     COLD,
@@ -10,11 +11,13 @@ pub enum DamageType {
     SHOCK,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum DamageSize {
     SINGLETARGET,
     MULTITARGET,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum DamageTime {
     INSTANT,
     DOT,
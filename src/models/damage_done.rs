@@ -1,3 +1,5 @@
+use crate::models::damage::DamageSize;
+
 #[derive(Default)]
 pub struct DamageDone {
     dot: f32,
@@ -21,4 +23,58 @@ impl DamageDone {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Multiplier on a hit of `size`, from damage done to every hit plus damage done with single target or area of effect hits.
+    pub fn get_multiplier(&self, size: &DamageSize) -> f32 {
+        let sized = match size {
+            DamageSize::SINGLETARGET => self.single_target,
+            DamageSize::MULTITARGET => self.aoe,
+        };
+        1.0 + (self.global + sized) / 100.0
+    }
+
+    /// Multiplier on a pet attack of `size`, which also benefits from pet damage done.
+    pub fn get_pet_multiplier(&self, size: &DamageSize) -> f32 {
+        self.get_multiplier(size) + self.pet / 100.0
+    }
+
+    pub fn add_to_global(&mut self, percent: f32) {
+        self.global += percent;
+    }
+
+    pub fn add_to_single_target(&mut self, percent: f32) {
+        self.single_target += percent;
+    }
+
+    pub fn add_to_aoe(&mut self, percent: f32) {
+        self.aoe += percent;
+    }
+
+    pub fn add_to_pet(&mut self, percent: f32) {
+        self.pet += percent;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiplier_applies_only_matching_size() {
+        let mut damage_done = DamageDone::new();
+        damage_done.add_to_global(10.0);
+        damage_done.add_to_single_target(6.0);
+        damage_done.add_to_aoe(4.0);
+        assert_eq!(damage_done.get_multiplier(&DamageSize::SINGLETARGET), 1.16);
+        assert_eq!(damage_done.get_multiplier(&DamageSize::MULTITARGET), 1.14);
+    }
+
+    #[test]
+    fn test_pet_damage_done_applies_only_to_pets() {
+        let mut damage_done = DamageDone::new();
+        damage_done.add_to_global(10.0);
+        damage_done.add_to_pet(5.0);
+        assert_eq!(damage_done.get_multiplier(&DamageSize::SINGLETARGET), 1.1);
+        assert_eq!(damage_done.get_pet_multiplier(&DamageSize::SINGLETARGET), 1.15);
+    }
 }
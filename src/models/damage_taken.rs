@@ -16,6 +16,17 @@ impl Resistance {
         ((self.additive as f32 / armour_cap as f32) * (1.0 + self.multiplicative)).min(ARMOUR_MAXIMUM)
     }
 
+    /// Resistance above what is needed to reach `ARMOUR_MAXIMUM`.
+    pub fn get_overcap(&self) -> u32 {
+        self.get_overcap_with_level(EFFECTIVE_LEVEL)
+    }
+
+    pub fn get_overcap_with_level(&self, level: u8) -> u32 {
+        let armour_cap = level as u32 * 500;
+        let needed = (ARMOUR_MAXIMUM * armour_cap as f32 / (1.0 + self.multiplicative)).ceil() as u32;
+        self.additive.saturating_sub(needed)
+    }
+
     pub fn add_to_additive(&mut self, value: u32) {
         self.additive += value;
     }
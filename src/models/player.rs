@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
-use crate::data::{armour::*, item_type::*, power::*, skill::*, traits::*};
+use crate::data::{armour::*, item_type::*, power::*, resource::FoodBuff, skill::*, traits::*};
 
+#[derive(Clone)]
 pub struct Player {
     id: u32,
     gear: Loadout,
@@ -9,6 +10,7 @@ pub struct Player {
     backup_abilities: Vec<u32>,
     active_bar: ActiveBar,
     buffs: HashMap<u32, u8>,
+    buff_sources: HashMap<u32, HashMap<u32, u8>>,
     attributes: (u8, u8, u8),
 }
 
@@ -21,7 +23,8 @@ impl Player {
             backup_abilities: Vec::new(),
             active_bar: ActiveBar::Primary,
             buffs: HashMap::new(),
-            attributes: (0, 0, 0)
+            buff_sources: HashMap::new(),
+            attributes: (0, 0, 0),
         }
     }
 
@@ -75,10 +78,18 @@ impl Player {
         self.gear.get_number_of_set(set_id, &self.active_bar)
     }
 
+    pub fn get_number_of_set_on_bar(&self, set_id: &u16, bar: &ActiveBar) -> u8 {
+        self.gear.get_number_of_set(set_id, bar)
+    }
+
     pub fn set_gear_piece(&mut self, slot: &GearSlot, gear: GearPiece) {
         self.gear.set_gear_piece(slot, gear);
     }
 
+    pub fn remove_gear_piece(&mut self, slot: &GearSlot) -> Option<GearPiece> {
+        self.gear.remove_gear_piece(slot)
+    }
+
     pub fn set_skills(&mut self, bar: &ActiveBar, skills: Vec<u32>) {
         match bar {
             ActiveBar::Primary => self.primary_abilities = skills,
@@ -90,14 +101,51 @@ impl Player {
         &self.buffs
     }
     
+    /// Adds a buff applied by the player themselves.
     pub fn add_buff(&mut self, id: u32, stacks: u8) {
-        self.buffs.insert(id, stacks);
+        self.add_buff_from_source(id, self.id, stacks);
+    }
+
+    /// Adds a buff applied by `source`. The same buff from several sources does not stack, the highest stack count is used.
+    pub fn add_buff_from_source(&mut self, id: u32, source: u32, stacks: u8) {
+        self.buff_sources.entry(id).or_default().insert(source, stacks);
+        self.update_buff_stacks(id);
     }
 
+    /// Removes the buff from every source.
     pub fn remove_buff(&mut self, id: &u32) {
+        self.buff_sources.remove(id);
         self.buffs.remove(id);
     }
 
+    /// Removes the buff applied by `source`. Returns true if the buff is still active from another source.
+    pub fn remove_buff_from_source(&mut self, id: &u32, source: &u32) -> bool {
+        if let Some(sources) = self.buff_sources.get_mut(id) {
+            sources.remove(source);
+            if sources.is_empty() {
+                self.buff_sources.remove(id);
+            }
+        }
+        self.update_buff_stacks(*id);
+        self.buffs.contains_key(id)
+    }
+
+    pub fn get_buff_sources(&self, id: &u32) -> Vec<u32> {
+        self.buff_sources.get(id).map(|sources| sources.keys().copied().collect()).unwrap_or_default()
+    }
+
+    fn update_buff_stacks(&mut self, id: u32) {
+        match self.buff_sources.get(&id).and_then(|sources| sources.values().max()) {
+            Some(stacks) => {self.buffs.insert(id, *stacks);},
+            None => {self.buffs.remove(&id);},
+        }
+    }
+
+    /// The active food buff. Characters only ever keep one.
+    pub fn get_food(&self) -> Option<u32> {
+        self.buffs.keys().copied().find(FoodBuff::is_valid_source)
+    }
+
     pub fn modify_stacks(&mut self, id: u32, stacks: u8) {
         self.add_buff(id, stacks);
     }
@@ -147,11 +195,16 @@ impl Player {
     }
 
     pub fn get_active_sets_counts(&self) -> Vec<ActiveSet> {
-        let gear = self.get_active_gear();
+        self.get_sets_counts_on_bar(&self.active_bar)
+    }
+
+    /// Pieces of every set worn with `bar` active, whether or not it is the active bar.
+    pub fn get_sets_counts_on_bar(&self, bar: &ActiveBar) -> Vec<ActiveSet> {
+        let gear = self.gear.get_active_gear(bar);
         let mut sets: HashMap<u16, u8> = HashMap::new();
         for gear_piece in gear {
             if let Some(set_id) = gear_piece.set_id {
-                *sets.entry(set_id).or_insert(0) += 1;
+                *sets.entry(set_id).or_insert(0) += gear_piece.get_set_piece_weight();
             }
         }
         sets.into_iter()
@@ -160,12 +213,13 @@ impl Player {
     }
 }
 
+#[derive(Debug, PartialEq, Clone)]
 pub struct ActiveSet {
     pub set_id: u32,
     pub count: u8,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Loadout {
     pub head: Option<GearPiece>,
     pub shoulders: Option<GearPiece>,
@@ -250,6 +304,28 @@ impl Loadout {
         };
     }
 
+    pub fn remove_gear_piece(&mut self, slot: &GearSlot) -> Option<GearPiece> {
+        let option = match slot {
+            GearSlot::Head => &mut self.head,
+            GearSlot::Shoulders => &mut self.shoulders,
+            GearSlot::Chest => &mut self.chest,
+            GearSlot::Hands => &mut self.hands,
+            GearSlot::Waist => &mut self.waist,
+            GearSlot::Legs => &mut self.legs,
+            GearSlot::Feet => &mut self.feet,
+            GearSlot::Necklace => &mut self.necklace,
+            GearSlot::Ring1 => &mut self.ring1,
+            GearSlot::Ring2 => &mut self.ring2,
+            GearSlot::MainHand => &mut self.main_hand,
+            GearSlot::OffHand => &mut self.off_hand,
+            GearSlot::Poison => &mut self.poison,
+            GearSlot::MainHandBackup => &mut self.main_hand_backup,
+            GearSlot::OffHandBackup => &mut self.off_hand_backup,
+            GearSlot::BackupPoison => &mut self.poison_backup,
+        };
+        option.take()
+    }
+
     pub fn get_active_gear(&self, active_bar: &ActiveBar) -> Vec<&GearPiece> {
         let slots: &[GearSlot] = match active_bar {
             ActiveBar::Primary => &[
@@ -348,9 +424,9 @@ impl Loadout {
     pub fn get_number_of_set(&self, set_id: &u16, active_bar: &ActiveBar) -> u8 {
         self.get_active_gear(active_bar)
             .iter()
-            .filter_map(|g| g.set_id)
-            .filter(|i| i == set_id)
-            .count() as u8
+            .filter(|g| g.set_id.as_ref() == Some(set_id))
+            .map(|g| g.get_set_piece_weight())
+            .sum()
     }
 
     pub fn get_total_armour(&self, active_bar: &ActiveBar) -> u32 {
@@ -358,14 +434,14 @@ impl Loadout {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct GearEnchant {
     pub glyph: EnchantType,
     pub effective_level: u8,
     pub quality: ItemQuality,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct GearPiece {
     pub item_id: u32,
     pub effective_level: u8,
@@ -380,6 +456,11 @@ impl GearPiece {
         ITEM_TYPES.get(&self.item_id)
     }
 
+    /// Two-handed weapons, staves and bows count as two pieces of their set.
+    pub fn get_set_piece_weight(&self) -> u8 {
+        if is_two_handed_weapon_option(self.get_item_type()) {2} else {1}
+    }
+
     pub fn get_item_trait(&self) -> Option<&GearTrait> {
         self.gear_trait.as_ref()
     }
@@ -425,6 +506,7 @@ impl GearPiece {
             GearTrait::JewelryProtective => get_jewelry_protective_value(quality),
             GearTrait::JewelrySwift => get_jewelry_swift_value(quality),
 
+            // Triune has a value per resource, see get_jewelry_triune_value
             GearTrait::JewelryTriune => return None,
 
             _ => return None,
@@ -434,12 +516,22 @@ impl GearPiece {
             && is_two_handed_weapon(item_type)
             && weapon_trait_doubles(&trait_)
         {
-            value *= 2.0;
+            value = double_weapon_trait_value(trait_, value);
         }
 
         Some(value)
     }
 
+    /// Multiplier applied to this piece's glyph by the Infused trait of its gear type.
+    pub fn get_enchant_multiplier(&self) -> f32 {
+        match self.gear_trait {
+            Some(GearTrait::WeaponInfused) => get_weapon_infused_value(&self.quality),
+            Some(GearTrait::ArmorInfused) => get_armor_infused_value(&self.quality),
+            Some(GearTrait::JewelryInfused) => get_jewelry_infused_value(&self.quality),
+            _ => 1.0,
+        }
+    }
+
     pub fn get_weapon_power(&self, gear_slot: &GearSlot) -> u32 {
         let base_power = self
             .get_item_type()
@@ -533,12 +625,23 @@ pub fn get_trait_value_for_item(gear: &GearPiece) -> Option<f32> {
         && is_two_handed_weapon(item_type)
         && weapon_trait_doubles(&trait_)
     {
-        value *= 2.0;
+        value = double_weapon_trait_value(trait_, value);
     }
 
     Some(value)
 }
 
+/// Two-handed weapons double the bonus of their trait. Traits given as a multiplier double the part above 1.
+fn double_weapon_trait_value(trait_: &GearTrait, value: f32) -> f32 {
+    match trait_ {
+        GearTrait::WeaponPowered
+        | GearTrait::WeaponCharged
+        | GearTrait::WeaponTraining
+        | GearTrait::WeaponDecisive => 1.0 + (value - 1.0) * 2.0,
+        _ => value * 2.0,
+    }
+}
+
 pub fn get_armour_enchant_multiplier(slot: &GearSlot) -> f32 {
     match slot {
         GearSlot::Head | GearSlot::OffHand | GearSlot::OffHandBackup | GearSlot::Chest | GearSlot::Legs => 1.0,
@@ -1,6 +1,6 @@
 use crate::models::LEVEL;
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Power {
     additive: u32,
     multiplicative: f32,
@@ -15,11 +15,24 @@ impl Power {
 
     pub fn calculate_with_level(&self, level: u8) -> u32 {
         let base =
-            level as u32 * 20
+            self.get_base_with_level(level)
             + self.additive;
         (base as f32 * (1.0 + self.multiplicative)).round() as u32 + self.bloodthirsty
     }
 
+    /// Power every character has before any additive sources.
+    pub fn get_base(&self) -> u32 {
+        self.get_base_with_level(LEVEL)
+    }
+
+    pub fn get_base_with_level(&self, level: u8) -> u32 {
+        level as u32 * 20
+    }
+
+    pub fn get_multiplicative(&self) -> f32 {
+        self.multiplicative
+    }
+
     pub fn add_to_additive(&mut self, value: u32) {
         self.additive += value;
     }
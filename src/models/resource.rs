@@ -1,12 +1,13 @@
 use crate::{models::LEVEL};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum PlayerAttributeType {
     Health,
     Magicka,
     Stamina,
 }
 
+#[derive(Clone)]
 pub struct PlayerMaxResource {
     resource_type: PlayerAttributeType,
     attribute: u8,
@@ -16,17 +17,21 @@ pub struct PlayerMaxResource {
 
 impl PlayerMaxResource {
     pub fn calculate(&self) -> u32 {
+        let base = self.get_base() + self.additive;
+
+        (base as f32 * (1.0 + self.multiplicative)).round() as u32
+    }
+
+    /// Max resource from level and attributes, before any additive sources.
+    pub fn get_base(&self) -> u32 {
         let (level_coeff, attr_coeff) = match self.resource_type {
             PlayerAttributeType::Health => (300, 122),
             PlayerAttributeType::Magicka | PlayerAttributeType::Stamina => (220, 111),
         };
 
-        let base = level_coeff * LEVEL as u32
+        level_coeff * LEVEL as u32
             + 1000
             + attr_coeff * self.attribute as u32
-            + self.additive;
-
-        (base as f32 * (1.0 + self.multiplicative)).round() as u32
     }
 
     pub fn add_to_additive(&mut self, value: u32) {
@@ -64,6 +69,45 @@ impl PlayerMaxResource {
     }
 }
 
+#[derive(Clone)]
+pub struct PlayerRecovery {
+    resource_type: PlayerAttributeType,
+    additive: u32,
+    multiplicative: f32,
+}
+
+impl PlayerRecovery {
+    pub fn calculate(&self) -> u32 {
+        let base = match self.resource_type {
+            PlayerAttributeType::Health => 309,
+            PlayerAttributeType::Magicka | PlayerAttributeType::Stamina => 514,
+        } + self.additive;
+
+        (base as f32 * (1.0 + self.multiplicative)).round() as u32
+    }
+
+    pub fn add_to_additive(&mut self, value: u32) {
+        self.additive += value;
+    }
+
+    pub fn add_to_multiplicative(&mut self, value: f32) {
+        self.multiplicative += value;
+    }
+
+    pub fn reset(&mut self) {
+        self.additive = 0;
+        self.multiplicative = 0.0;
+    }
+
+    pub fn new(resource_type: PlayerAttributeType) -> Self {
+        PlayerRecovery {
+            resource_type,
+            additive: 0,
+            multiplicative: 0.0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +141,11 @@ mod tests {
         h.multiplicative = 0.12; // undaunted passive + heavy armour passive x5
         assert_eq!(h.calculate(), 33456u32); // compared with tested value in game
     }
+
+    #[test]
+    fn test_calculate_default_recovery() {
+        assert_eq!(PlayerRecovery::new(PlayerAttributeType::Health).calculate(), 309u32);
+        assert_eq!(PlayerRecovery::new(PlayerAttributeType::Magicka).calculate(), 514u32);
+        assert_eq!(PlayerRecovery::new(PlayerAttributeType::Stamina).calculate(), 514u32);
+    }
 }
\ No newline at end of file